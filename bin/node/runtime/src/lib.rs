@@ -32,10 +32,10 @@ use frame_support::{
 	pallet_prelude::Get,
 	parameter_types,
 	traits::{
-		fungible::ItemOf, AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32,
-		Currency, EitherOfDiverse, EqualPrivilegeOnly, Everything, Imbalance, InstanceFilter,
-		KeyOwnerProofSystem, LockIdentifier, Nothing, OnUnbalanced, U128CurrencyToVote,
-		WithdrawReasons,
+		fungible::ItemOf, tokens::nonfungibles_v2::Inspect as NonFungiblesInspect,
+		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32, Contains, Currency,
+		EitherOfDiverse, EqualPrivilegeOnly, Imbalance, InstanceFilter, KeyOwnerProofSystem,
+		LockIdentifier, Nothing, OnUnbalanced, U128CurrencyToVote, WithdrawReasons,
 	},
 	weights::{
 		constants::{
@@ -206,8 +206,41 @@ parameter_types! {
 
 const_assert!(NORMAL_DISPATCH_RATIO.deconstruct() >= AVERAGE_ON_INITIALIZE_RATIO.deconstruct());
 
+/// Filters out the `pallet_nfts` calls whose weights are still the hand-written placeholders
+/// rather than the benchmarked ones, see `pallet_nfts::weights`.
+pub struct BaseFilter;
+impl Contains<RuntimeCall> for BaseFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		!matches!(
+			call,
+			RuntimeCall::Nfts(
+				pallet_nfts::Call::set_attribute { .. } |
+					pallet_nfts::Call::force_set_attribute { .. } |
+					pallet_nfts::Call::set_typed_attribute { .. } |
+					pallet_nfts::Call::recalculate_attribute_deposit { .. } |
+					pallet_nfts::Call::clear_item_namespace { .. } |
+					pallet_nfts::Call::transfer_item_attributes { .. } |
+					pallet_nfts::Call::clear_orphaned_attributes { .. } |
+					pallet_nfts::Call::set_delegate_attributes_sponsorship { .. } |
+					pallet_nfts::Call::set_large_attribute { .. } |
+					pallet_nfts::Call::lock_attribute { .. } |
+					pallet_nfts::Call::set_attribute_schema { .. } |
+					pallet_nfts::Call::set_attributes { .. } |
+					pallet_nfts::Call::set_attribute_with_expiry { .. } |
+					pallet_nfts::Call::set_attribute_if { .. } |
+					pallet_nfts::Call::move_attribute { .. } |
+					pallet_nfts::Call::clear_collection_attributes { .. } |
+					pallet_nfts::Call::force_set_attribute_with_deposit { .. } |
+					pallet_nfts::Call::add_collection_attribute_editor { .. } |
+					pallet_nfts::Call::remove_collection_attribute_editor { .. } |
+					pallet_nfts::Call::set_compressed_attribute { .. }
+			)
+		)
+	}
+}
+
 impl frame_system::Config for Runtime {
-	type BaseCallFilter = Everything;
+	type BaseCallFilter = BaseFilter;
 	type BlockWeights = RuntimeBlockWeights;
 	type BlockLength = RuntimeBlockLength;
 	type DbWeight = RocksDbWeight;
@@ -1545,6 +1578,8 @@ parameter_types! {
 	pub const ValueLimit: u32 = 256;
 	pub const ApprovalsLimit: u32 = 20;
 	pub const ItemAttributesApprovalsLimit: u32 = 20;
+	pub const ItemAttributesApprovalsNearLimitThreshold: u32 = 2;
+	pub const CollectionAttributeEditorsLimit: u32 = 20;
 	pub const MaxTips: u32 = 10;
 	pub const MaxDeadlineDuration: BlockNumber = 12 * 30 * DAYS;
 }
@@ -1573,6 +1608,14 @@ impl pallet_uniques::Config for Runtime {
 parameter_types! {
 	pub Features: PalletFeatures = PalletFeatures::all_enabled();
 	pub const MaxAttributesPerCall: u32 = 10;
+	pub const MaxAttributesPerItem: u32 = 100;
+	pub const MaxAttributeChunks: u32 = 16;
+	pub const MaxAttributeSchemas: u32 = 64;
+	pub const MaxAttributeValueOptions: u32 = 32;
+	pub const AutoMoveItemAttributes: bool = false;
+	pub const MinAttributeValueLen: u32 = 0;
+	pub ReservedKeyPrefixes: Vec<Vec<u8>> = Vec::new();
+	pub const MaxAttributeWritesPerBlock: Option<u32> = None;
 }
 
 impl pallet_nfts::Config for Runtime {
@@ -1591,9 +1634,20 @@ impl pallet_nfts::Config for Runtime {
 	type ValueLimit = ValueLimit;
 	type ApprovalsLimit = ApprovalsLimit;
 	type ItemAttributesApprovalsLimit = ItemAttributesApprovalsLimit;
+	type ItemAttributesApprovalsNearLimitThreshold = ItemAttributesApprovalsNearLimitThreshold;
+	type CollectionAttributeEditorsLimit = CollectionAttributeEditorsLimit;
 	type MaxTips = MaxTips;
 	type MaxDeadlineDuration = MaxDeadlineDuration;
 	type MaxAttributesPerCall = MaxAttributesPerCall;
+	type MaxAttributesPerItem = MaxAttributesPerItem;
+	type MaxAttributeChunks = MaxAttributeChunks;
+	type AttributeCompressor = ();
+	type MaxAttributeSchemas = MaxAttributeSchemas;
+	type MaxAttributeValueOptions = MaxAttributeValueOptions;
+	type AutoMoveItemAttributes = AutoMoveItemAttributes;
+	type MinAttributeValueLen = MinAttributeValueLen;
+	type ReservedKeyPrefixes = ReservedKeyPrefixes;
+	type MaxAttributeWritesPerBlock = MaxAttributeWritesPerBlock;
 	type Features = Features;
 	type OffchainSignature = Signature;
 	type OffchainPublic = <Signature as traits::Verify>::Signer;
@@ -1602,6 +1656,9 @@ impl pallet_nfts::Config for Runtime {
 	type Helper = ();
 	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
 	type Locker = ();
+	type NamespacePrecedence = pallet_nfts::DefaultNamespacePrecedence;
+	type AttributeDepositHandler = pallet_nfts::CurrencyDepositHandler;
+	type OnLostDeposit = ();
 }
 
 impl pallet_transaction_storage::Config for Runtime {
@@ -1834,6 +1891,7 @@ type Migrations = (
 	pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
+	pallet_nfts::migration::v1::MigrateToV1<Runtime>,
 );
 
 /// MMR helper types.
@@ -2007,6 +2065,312 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_nfts_rpc_runtime_api::NftsApi<Block, AccountId, u32, u32, Balance> for Runtime {
+		fn owner(collection: u32, item: u32) -> Option<AccountId> {
+			Nfts::owner(collection, item)
+		}
+
+		fn collection_owner(collection: u32) -> Option<AccountId> {
+			Nfts::collection_owner(collection)
+		}
+
+		fn attribute(
+			collection: u32,
+			item: u32,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Option<Vec<u8>> {
+			<Nfts as NonFungiblesInspect<AccountId>>::attribute(&collection, &item, &namespace, &key)
+		}
+
+		fn item_attributes(
+			collection: u32,
+			item: u32,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(Vec<u8>, Vec<u8>)>, Option<Vec<u8>>) {
+			Nfts::item_attributes(collection, item, namespace, start_key, limit)
+		}
+
+		fn collection_attribute(collection: u32, key: Vec<u8>) -> Option<Vec<u8>> {
+			<Nfts as NonFungiblesInspect<AccountId>>::collection_attribute(&collection, &key)
+		}
+
+		fn item_metadata(collection: u32, item: u32) -> Option<Vec<u8>> {
+			Nfts::item_metadata(collection, item)
+		}
+
+		fn collection_metadata(collection: u32) -> Option<Vec<u8>> {
+			Nfts::collection_metadata(collection)
+		}
+
+		fn collection_attributes_count(collection: u32) -> Option<u32> {
+			Nfts::collection_attributes_count(collection)
+		}
+
+		fn owners(collection: u32, items: Vec<u32>) -> Vec<Option<AccountId>> {
+			items.into_iter().map(|item| Nfts::owner(collection, item)).collect()
+		}
+
+		fn resolve_attribute_namespace(
+			collection: u32,
+			item: u32,
+			key: Vec<u8>,
+		) -> Option<pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>> {
+			Nfts::resolve_attribute_namespace(collection, item, key)
+		}
+
+		fn item_settings(collection: u32, item: u32) -> Option<u64> {
+			Nfts::item_settings(collection, item)
+		}
+
+		fn typed_attribute(
+			collection: u32,
+			item: u32,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Option<(Vec<u8>, pallet_nfts_rpc_runtime_api::AttributeValueType)> {
+			Nfts::typed_attribute(collection, item, namespace, key)
+		}
+
+		fn has_attribute(
+			collection: u32,
+			item: u32,
+			key: Vec<u8>,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+		) -> bool {
+			Nfts::has_attribute(collection, item, key, namespace)
+		}
+
+		fn attribute_key_limit() -> u32 {
+			Nfts::attribute_key_limit()
+		}
+
+		fn attribute_value_limit() -> u32 {
+			Nfts::attribute_value_limit()
+		}
+
+		fn attribute_namespaces(
+			collection: u32,
+			item: u32,
+			key: Vec<u8>,
+		) -> Vec<(pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>, Vec<u8>)> {
+			Nfts::attribute_namespaces(collection, item, key)
+		}
+
+		fn account_items(
+			account: AccountId,
+			collection: u32,
+			start: Option<u32>,
+			limit: u32,
+		) -> (Vec<u32>, Option<u32>) {
+			Nfts::account_items(account, collection, start, limit)
+		}
+
+		fn can_set_attribute(
+			account: AccountId,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+			collection: u32,
+			item: Option<u32>,
+		) -> bool {
+			Nfts::can_set_attribute(&account, &namespace, &collection, &item).unwrap_or(false)
+		}
+
+		fn item_attribute_approvals(collection: u32, item: u32) -> Vec<AccountId> {
+			Nfts::item_attribute_approvals(collection, item)
+		}
+
+		fn item_attributes_filtered(
+			collection: u32,
+			item: u32,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+			key_prefix: Vec<u8>,
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(Vec<u8>, Vec<u8>)>, Option<Vec<u8>>) {
+			Nfts::item_attributes_filtered(collection, item, namespace, key_prefix, start_key, limit)
+		}
+
+		fn compute_attribute_deposit(
+			collection: u32,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+			key_len: u32,
+			value_len: u32,
+		) -> Option<Balance> {
+			Nfts::compute_attribute_deposit(collection, namespace, key_len, value_len)
+		}
+
+		fn collection_owner_deposit(collection: u32) -> Option<Balance> {
+			Nfts::collection_owner_deposit(collection)
+		}
+
+		fn item_info(
+			collection: u32,
+			item: u32,
+		) -> Option<pallet_nfts_rpc_runtime_api::ItemInfo<AccountId>> {
+			Nfts::item_info(collection, item)
+		}
+
+		fn item_attribute_changes(
+			collection: u32,
+			item: u32,
+		) -> Vec<pallet_nfts_rpc_runtime_api::AttributeChange<AccountId>> {
+			Nfts::item_attribute_changes(collection, item)
+		}
+
+		fn namespace_attribute_count(
+			collection: u32,
+			kind: pallet_nfts_rpc_runtime_api::NamespaceDiscriminant,
+		) -> Option<u32> {
+			Nfts::namespace_attribute_count(collection, kind)
+		}
+
+		fn large_attribute(
+			collection: u32,
+			item: u32,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Option<Vec<u8>> {
+			Nfts::large_attribute(collection, item, namespace, key)
+		}
+
+		fn simulate_set_attribute(
+			origin: AccountId,
+			collection: u32,
+			maybe_item: Option<u32>,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+			value: Vec<u8>,
+		) -> Result<
+			pallet_nfts_rpc_runtime_api::SetAttributeEffect<AccountId, Balance>,
+			sp_runtime::DispatchError,
+		> {
+			Nfts::simulate_set_attribute(origin, collection, maybe_item, namespace, key, value)
+		}
+
+		fn attribute_checked(
+			collection: u32,
+			item: u32,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Result<Option<Vec<u8>>, pallet_nfts_rpc_runtime_api::ItemQueryError> {
+			use pallet_nfts_rpc_runtime_api::ItemQueryError;
+			Nfts::attribute_checked(collection, item, key, namespace).map_err(|e| match e {
+				pallet_nfts::Error::<Runtime>::UnknownCollection => ItemQueryError::UnknownCollection,
+				_ => ItemQueryError::UnknownItem,
+			})
+		}
+
+		fn item_attribute_storage_key(
+			collection: u32,
+			item: u32,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Option<Vec<u8>> {
+			Nfts::item_attribute_storage_key(collection, item, key, namespace)
+		}
+
+		fn items_attribute(
+			collection: u32,
+			items: Vec<u32>,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Vec<Option<Vec<u8>>> {
+			Nfts::items_attribute(collection, items, key, namespace)
+		}
+
+		fn item_attributes_locked(collection: u32, item: u32) -> bool {
+			Nfts::item_attributes_locked(collection, item)
+		}
+
+		fn item_attribute_delegates(collection: u32, item: u32) -> Vec<(AccountId, u32)> {
+			Nfts::item_attribute_delegates(collection, item)
+		}
+
+		fn item_attribute_accounts(collection: u32, item: u32) -> Vec<AccountId> {
+			Nfts::item_attribute_accounts(collection, item)
+		}
+
+		fn item_metadata_uri(collection: u32, item: u32, key: Vec<u8>) -> Option<Vec<u8>> {
+			Nfts::item_metadata_uri(collection, item, key)
+		}
+
+		fn collection_attributes(
+			collection: u32,
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(Vec<u8>, Vec<u8>)>, Option<Vec<u8>>) {
+			Nfts::collection_attributes(collection, start_key, limit)
+		}
+
+		fn attribute_last_set(
+			collection: u32,
+			item: u32,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Option<BlockNumber> {
+			Nfts::attribute_last_set(collection, item, key, namespace)
+		}
+
+		fn is_item_attribute_delegate(collection: u32, item: u32, account: AccountId) -> bool {
+			Nfts::is_item_attribute_delegate(collection, item, account)
+		}
+
+		fn namespace_precedence_order(
+			collection: u32,
+			item: u32,
+			key: Vec<u8>,
+		) -> Vec<pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>> {
+			Nfts::namespace_precedence_order(collection, item, key)
+		}
+
+		fn item_has_any_attribute(collection: u32, item: u32) -> bool {
+			Nfts::item_has_any_attribute(collection, item)
+		}
+
+		fn item_total_attribute_deposit(collection: u32, item: u32) -> Balance {
+			Nfts::item_total_attribute_deposit(collection, item)
+		}
+
+		fn collection_namespace_attributes(
+			collection: u32,
+			namespace: pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+			start: Option<u32>,
+			limit: u32,
+		) -> (Vec<(u32, Vec<u8>)>, Option<u32>) {
+			Nfts::collection_namespace_attributes(collection, namespace, key, start, limit)
+		}
+
+		fn item_attribute_resolved(
+			collection: u32,
+			item: u32,
+			key: Vec<u8>,
+		) -> Option<(Vec<u8>, pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>)> {
+			Nfts::item_attribute_resolved(collection, item, key)
+		}
+
+		fn item_royalty(collection: u32, item: u32, key: Vec<u8>) -> Option<(AccountId, Perbill)> {
+			Nfts::item_royalty(collection, item, key)
+		}
+
+		fn item_attribute_any(
+			collection: u32,
+			item: u32,
+			key: Vec<u8>,
+		) -> Option<(Vec<u8>, pallet_nfts_rpc_runtime_api::AttributeNamespace<AccountId>)> {
+			Nfts::item_attribute_any(collection, item, key)
+		}
+
+		fn collection_attribute_with_deposit(
+			collection: u32,
+			key: Vec<u8>,
+		) -> Option<(Vec<u8>, Balance)> {
+			Nfts::collection_attribute_with_deposit(&collection, &key)
+		}
+	}
+
 	impl pallet_staking_runtime_api::StakingApi<Block, Balance> for Runtime {
 		fn nominations_quota(balance: Balance) -> u32 {
 			Staking::api_nominations_quota(balance)
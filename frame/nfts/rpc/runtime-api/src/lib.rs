@@ -0,0 +1,341 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the NFTs pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use frame_support::{dispatch::DispatchError, sp_runtime::Perbill};
+use sp_api::NumberFor;
+use sp_std::vec::Vec;
+
+pub use frame_support::traits::tokens::AttributeNamespace;
+pub use pallet_nfts::{
+	AttributeChange, AttributeValueType, DepositDelta, ItemInfo, ItemQueryError,
+	NamespaceDiscriminant, SetAttributeEffect,
+};
+
+pub use pallet_nfts::well_known_keys;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for accessing information about the NFTs pallet.
+	pub trait NftsApi<AccountId, CollectionId, ItemId, Balance>
+		where
+			AccountId: Codec,
+			CollectionId: Codec,
+			ItemId: Codec,
+			Balance: Codec,
+	{
+		/// Returns the owner of the `item` within the `collection`, if the item exists.
+		fn owner(collection: CollectionId, item: ItemId) -> Option<AccountId>;
+
+		/// Returns the owner of the `collection`, if the collection exists.
+		fn collection_owner(collection: CollectionId) -> Option<AccountId>;
+
+		/// Returns the value of the `item`'s attribute stored under `key` within the `namespace`.
+		fn attribute(
+			collection: CollectionId,
+			item: ItemId,
+			namespace: AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Option<Vec<u8>>;
+
+		/// Returns up to `limit` attributes of the `item` within the `namespace`, starting right
+		/// after `start_key`, along with the key to continue from if there are more attributes.
+		fn item_attributes(
+			collection: CollectionId,
+			item: ItemId,
+			namespace: AttributeNamespace<AccountId>,
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(Vec<u8>, Vec<u8>)>, Option<Vec<u8>>);
+
+		/// Returns the value of the `collection`'s attribute stored under `key`.
+		fn collection_attribute(collection: CollectionId, key: Vec<u8>) -> Option<Vec<u8>>;
+
+		/// Returns the metadata of the `item` within the `collection`, if it was set.
+		fn item_metadata(collection: CollectionId, item: ItemId) -> Option<Vec<u8>>;
+
+		/// Returns the metadata of the `collection`, if it was set.
+		fn collection_metadata(collection: CollectionId) -> Option<Vec<u8>>;
+
+		/// Returns the number of attributes stored within the `collection`, or `None` if the
+		/// collection doesn't exist.
+		fn collection_attributes_count(collection: CollectionId) -> Option<u32>;
+
+		/// Returns the owners of the `items` within the `collection`, in the same order as the
+		/// `items` were provided.
+		fn owners(collection: CollectionId, items: Vec<ItemId>) -> Vec<Option<AccountId>>;
+
+		/// Returns the namespace the `key` attribute of the `item` is resolved from, or `None` if
+		/// the attribute doesn't exist in any namespace.
+		fn resolve_attribute_namespace(
+			collection: CollectionId,
+			item: ItemId,
+			key: Vec<u8>,
+		) -> Option<AttributeNamespace<AccountId>>;
+
+		/// Returns the bitflags of the `item`'s disabled settings, if the item exists.
+		fn item_settings(collection: CollectionId, item: ItemId) -> Option<u64>;
+
+		/// Returns the value of the `item`'s attribute stored under `key` within the `namespace`
+		/// along with the type of that value.
+		fn typed_attribute(
+			collection: CollectionId,
+			item: ItemId,
+			namespace: AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Option<(Vec<u8>, AttributeValueType)>;
+
+		/// Returns whether the `item`'s attribute is stored under `key` within the `namespace`,
+		/// without reading its value.
+		fn has_attribute(
+			collection: CollectionId,
+			item: ItemId,
+			key: Vec<u8>,
+			namespace: AttributeNamespace<AccountId>,
+		) -> bool;
+
+		/// Returns the maximum length of an attribute's key.
+		fn attribute_key_limit() -> u32;
+
+		/// Returns the maximum length of an attribute's value.
+		fn attribute_value_limit() -> u32;
+
+		/// Returns every namespace the `item`'s attribute stored under `key` is present in, along
+		/// with the value stored within that namespace.
+		fn attribute_namespaces(
+			collection: CollectionId,
+			item: ItemId,
+			key: Vec<u8>,
+		) -> Vec<(AttributeNamespace<AccountId>, Vec<u8>)>;
+
+		/// Returns up to `limit` items of the `collection` owned by the `account`, starting right
+		/// after the `start` item, along with the item to continue from if there are more left.
+		fn account_items(
+			account: AccountId,
+			collection: CollectionId,
+			start: Option<ItemId>,
+			limit: u32,
+		) -> (Vec<ItemId>, Option<ItemId>);
+
+		/// Returns whether the `account` is allowed to set attributes within the `namespace` of
+		/// the `collection` or of its `item`.
+		fn can_set_attribute(
+			account: AccountId,
+			namespace: AttributeNamespace<AccountId>,
+			collection: CollectionId,
+			item: Option<ItemId>,
+		) -> bool;
+
+		/// Returns the accounts approved to set attributes of the `item`.
+		fn item_attribute_approvals(collection: CollectionId, item: ItemId) -> Vec<AccountId>;
+
+		/// Returns up to `limit` attributes of the `item` within the `namespace` whose keys start
+		/// with the `key_prefix`, starting right after the `start_key`, along with the key to
+		/// continue from if there are more attributes left.
+		fn item_attributes_filtered(
+			collection: CollectionId,
+			item: ItemId,
+			namespace: AttributeNamespace<AccountId>,
+			key_prefix: Vec<u8>,
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(Vec<u8>, Vec<u8>)>, Option<Vec<u8>>);
+
+		/// Returns the deposit required to store an attribute with the key and the value of the
+		/// provided lengths within the `namespace`, if the `collection` exists.
+		fn compute_attribute_deposit(
+			collection: CollectionId,
+			namespace: AttributeNamespace<AccountId>,
+			key_len: u32,
+			value_len: u32,
+		) -> Option<Balance>;
+
+		/// Returns the total deposit reserved from the collection's owner, if the `collection`
+		/// exists.
+		fn collection_owner_deposit(collection: CollectionId) -> Option<Balance>;
+
+		/// Returns the owner, metadata, settings and the number of attributes of the `item`
+		/// within the `collection`, if the item exists.
+		fn item_info(collection: CollectionId, item: ItemId) -> Option<ItemInfo<AccountId>>;
+
+		/// Returns the changes of the `item`'s attributes within the `collection` made in the
+		/// block the call is executed at.
+		fn item_attribute_changes(
+			collection: CollectionId,
+			item: ItemId,
+		) -> Vec<AttributeChange<AccountId>>;
+
+		/// Returns the number of attributes stored within the `collection` in the namespaces of
+		/// the `kind`, if the collection exists.
+		fn namespace_attribute_count(
+			collection: CollectionId,
+			kind: NamespaceDiscriminant,
+		) -> Option<u32>;
+
+		/// Returns the value of the `item`'s attribute set with `set_large_attribute`,
+		/// reassembled from its chunks.
+		fn large_attribute(
+			collection: CollectionId,
+			item: ItemId,
+			namespace: AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Option<Vec<u8>>;
+
+		/// Simulates setting the attribute by the `origin` without persisting it, returning the
+		/// effect on the deposits or the error the call would fail with.
+		fn simulate_set_attribute(
+			origin: AccountId,
+			collection: CollectionId,
+			maybe_item: Option<ItemId>,
+			namespace: AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+			value: Vec<u8>,
+		) -> Result<SetAttributeEffect<AccountId, Balance>, DispatchError>;
+
+		/// Returns the value of the `item`'s attribute within the `namespace`, or an error if the
+		/// `collection` or the `item` doesn't exist.
+		fn attribute_checked(
+			collection: CollectionId,
+			item: ItemId,
+			namespace: AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Result<Option<Vec<u8>>, ItemQueryError>;
+
+		/// Returns the final storage key of the `item`'s attribute within the `namespace`, if the
+		/// `key` doesn't exceed the limit.
+		fn item_attribute_storage_key(
+			collection: CollectionId,
+			item: ItemId,
+			namespace: AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Option<Vec<u8>>;
+
+		/// Returns the value of the `key` attribute within the `namespace` for each of the
+		/// `items`, in the same order as the `items` were provided.
+		fn items_attribute(
+			collection: CollectionId,
+			items: Vec<ItemId>,
+			namespace: AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Vec<Option<Vec<u8>>>;
+
+		/// Returns whether the `CollectionOwner` namespace attributes of the `item` are locked.
+		fn item_attributes_locked(collection: CollectionId, item: ItemId) -> bool;
+
+		/// Returns the accounts approved to set the `item`'s attributes along with the number of
+		/// attributes each of them has set.
+		fn item_attribute_delegates(collection: CollectionId, item: ItemId) -> Vec<(AccountId, u32)>;
+
+		/// Returns the accounts holding any of the `item`'s attributes within their own
+		/// namespaces, both the approved delegates and the collection's attribute editors.
+		fn item_attribute_accounts(collection: CollectionId, item: ItemId) -> Vec<AccountId>;
+
+		/// Returns the value of the `key` attribute of the `item` resolved according to the
+		/// namespace precedence, falling back to the item's metadata.
+		fn item_metadata_uri(
+			collection: CollectionId,
+			item: ItemId,
+			key: Vec<u8>,
+		) -> Option<Vec<u8>>;
+
+		/// Returns up to `limit` attributes of the `collection` itself, starting right after
+		/// `start_key`, along with the key to continue from if there are more attributes.
+		fn collection_attributes(
+			collection: CollectionId,
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(Vec<u8>, Vec<u8>)>, Option<Vec<u8>>);
+
+		/// Returns the block at which the `item`'s attribute within the `namespace` was last set,
+		/// if the attribute exists.
+		fn attribute_last_set(
+			collection: CollectionId,
+			item: ItemId,
+			namespace: AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+		) -> Option<NumberFor<Block>>;
+
+		/// Returns whether the `account` is approved to set the `item`'s attributes.
+		fn is_item_attribute_delegate(
+			collection: CollectionId,
+			item: ItemId,
+			account: AccountId,
+		) -> bool;
+
+		/// Returns the namespaces consulted when the `key` attribute of the `item` is read without
+		/// specifying the namespace, in the order they are consulted.
+		fn namespace_precedence_order(
+			collection: CollectionId,
+			item: ItemId,
+			key: Vec<u8>,
+		) -> Vec<AttributeNamespace<AccountId>>;
+
+		/// Returns whether the `item` holds any attribute within any namespace.
+		fn item_has_any_attribute(collection: CollectionId, item: ItemId) -> bool;
+
+		/// Returns the sum of the deposits of the `item`'s attributes within all the namespaces.
+		///
+		/// Iterates over all the item's attributes.
+		fn item_total_attribute_deposit(collection: CollectionId, item: ItemId) -> Balance;
+
+		/// Returns up to `limit` values of the attribute stored under `key` within the `namespace`
+		/// across all the items of the `collection`, starting right after the `start` item, along
+		/// with the item to continue from if there are more values left.
+		fn collection_namespace_attributes(
+			collection: CollectionId,
+			namespace: AttributeNamespace<AccountId>,
+			key: Vec<u8>,
+			start: Option<ItemId>,
+			limit: u32,
+		) -> (Vec<(ItemId, Vec<u8>)>, Option<ItemId>);
+
+		/// Returns the value of the `item`'s attribute stored under `key` resolved according to
+		/// the namespace precedence, along with the namespace it was resolved from.
+		fn item_attribute_resolved(
+			collection: CollectionId,
+			item: ItemId,
+			key: Vec<u8>,
+		) -> Option<(Vec<u8>, AttributeNamespace<AccountId>)>;
+
+		/// Returns the royalty terms SCALE-encoded as `(AccountId, Permill)` within the `key`
+		/// attribute of the `item` resolved according to the namespace precedence.
+		fn item_royalty(
+			collection: CollectionId,
+			item: ItemId,
+			key: Vec<u8>,
+		) -> Option<(AccountId, Perbill)>;
+
+		/// Returns the value of the `item`'s attribute stored under `key` within any namespace,
+		/// consulting the namespace precedence first and the delegated accounts' namespaces
+		/// afterwards, along with the namespace it was found in.
+		fn item_attribute_any(
+			collection: CollectionId,
+			item: ItemId,
+			key: Vec<u8>,
+		) -> Option<(Vec<u8>, AttributeNamespace<AccountId>)>;
+
+		/// Returns the value of the `collection`'s attribute stored under `key` along with the
+		/// part of the collection owner's deposit it accounts for.
+		fn collection_attribute_with_deposit(
+			collection: CollectionId,
+			key: Vec<u8>,
+		) -> Option<(Vec<u8>, Balance)>;
+	}
+}
@@ -0,0 +1,2270 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the NFTs pallet.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use codec::{Codec, Decode, DecodeAll, Encode};
+use futures::{future, FutureExt, StreamExt};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{
+		error::{CallError, ErrorCode, ErrorObject},
+		SubscriptionResult,
+	},
+	SubscriptionSink,
+};
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{
+	traits::{Block as BlockT, NumberFor},
+	Perbill,
+};
+
+use pallet_nfts_rpc_runtime_api::well_known_keys;
+pub use pallet_nfts_rpc_runtime_api::{
+	AttributeChange, AttributeNamespace, AttributeValueType, DepositDelta, ItemInfo,
+	ItemQueryError, NamespaceDiscriminant, NftsApi as NftsRuntimeApi, SetAttributeEffect,
+};
+
+/// The maximum number of attributes that can be requested within a single page.
+pub const MAX_ATTRIBUTES_PAGE_SIZE: u32 = 1000;
+
+/// The maximum number of items whose owners can be requested at once.
+pub const MAX_OWNERS_PER_QUERY: u32 = 256;
+
+/// The maximum number of items whose attribute can be requested at once.
+pub const MAX_ITEMS_PER_ATTRIBUTE_QUERY: u32 = 256;
+
+/// The maximum number of an account's items that can be requested within a single page.
+pub const MAX_ACCOUNT_ITEMS_PAGE_SIZE: u32 = 1000;
+
+/// The maximum number of an item's attributes that can be compared across two blocks.
+pub const MAX_ATTRIBUTE_DIFF_KEYS: u32 = 10_000;
+
+/// The key of the attribute conventionally pointing to the item's off-chain metadata.
+pub const METADATA_URI_KEY: &[u8] = well_known_keys::EXTERNAL_URI;
+
+/// The key of the attribute conventionally holding the item's royalty terms.
+pub const ROYALTY_KEY: &[u8] = well_known_keys::ROYALTY;
+
+/// The default max size of the attribute keys and values returned within a single response.
+pub const DEFAULT_MAX_RESPONSE_SIZE: usize = 10 * 1024 * 1024;
+
+/// The opaque position to continue a paginated iteration from.
+///
+/// Serialized as a hex-encoded string. Returned along with a page when there are more entries
+/// left, to be passed back as is to get the next page.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct AttributeCursor(pub Bytes);
+
+impl From<Vec<u8>> for AttributeCursor {
+	fn from(raw: Vec<u8>) -> Self {
+		Self(raw.into())
+	}
+}
+
+impl From<AttributeCursor> for Vec<u8> {
+	fn from(cursor: AttributeCursor) -> Self {
+		cursor.0 .0
+	}
+}
+
+/// The maximum lengths of attributes' keys and values.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AttributeLimits {
+	/// The maximum length of an attribute key.
+	pub key_limit: u32,
+	/// The maximum length of an attribute value.
+	pub value_limit: u32,
+}
+
+/// The JSON representation of an `AttributeNamespace`.
+///
+/// Serialized as `{"pallet":null}`, `{"collectionOwner":null}`, `{"itemOwner":null}` or
+/// `{"account":"0x.."}`, where the account is hex-encoded the same way it's SCALE-encoded
+/// on-chain.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(from = "AttributeNamespaceRepr", into = "AttributeNamespaceRepr")]
+pub enum RpcAttributeNamespace {
+	/// An attribute was set by the pallet.
+	Pallet,
+	/// An attribute was set by collection's owner.
+	CollectionOwner,
+	/// An attribute was set by item's owner.
+	ItemOwner,
+	/// An attribute was set by pre-approved account, SCALE-encoded.
+	Account(Bytes),
+}
+
+impl RpcAttributeNamespace {
+	/// Converts into the `AttributeNamespace` by decoding the account, if any.
+	///
+	/// Fails if the account's encoding has any trailing bytes.
+	pub fn try_into_namespace<AccountId: Decode>(
+		self,
+	) -> Result<AttributeNamespace<AccountId>, codec::Error> {
+		Ok(match self {
+			Self::Pallet => AttributeNamespace::Pallet,
+			Self::CollectionOwner => AttributeNamespace::CollectionOwner,
+			Self::ItemOwner => AttributeNamespace::ItemOwner,
+			Self::Account(account) =>
+				AttributeNamespace::Account(AccountId::decode_all(&mut &*account)?),
+		})
+	}
+
+	/// Returns the kind of the namespace, ignoring the account, if any.
+	pub fn discriminant(&self) -> NamespaceDiscriminant {
+		match self {
+			Self::Pallet => NamespaceDiscriminant::Pallet,
+			Self::CollectionOwner => NamespaceDiscriminant::CollectionOwner,
+			Self::ItemOwner => NamespaceDiscriminant::ItemOwner,
+			Self::Account(_) => NamespaceDiscriminant::Account,
+		}
+	}
+}
+
+impl<AccountId: Encode> From<AttributeNamespace<AccountId>> for RpcAttributeNamespace {
+	fn from(namespace: AttributeNamespace<AccountId>) -> Self {
+		match namespace {
+			AttributeNamespace::Pallet => Self::Pallet,
+			AttributeNamespace::CollectionOwner => Self::CollectionOwner,
+			AttributeNamespace::ItemOwner => Self::ItemOwner,
+			AttributeNamespace::Account(account) => Self::Account(account.encode().into()),
+		}
+	}
+}
+
+/// The externally tagged serde representation of the `RpcAttributeNamespace`.
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum AttributeNamespaceRepr {
+	Pallet(()),
+	CollectionOwner(()),
+	ItemOwner(()),
+	Account(Bytes),
+}
+
+impl From<AttributeNamespaceRepr> for RpcAttributeNamespace {
+	fn from(repr: AttributeNamespaceRepr) -> Self {
+		match repr {
+			AttributeNamespaceRepr::Pallet(()) => Self::Pallet,
+			AttributeNamespaceRepr::CollectionOwner(()) => Self::CollectionOwner,
+			AttributeNamespaceRepr::ItemOwner(()) => Self::ItemOwner,
+			AttributeNamespaceRepr::Account(account) => Self::Account(account),
+		}
+	}
+}
+
+impl From<RpcAttributeNamespace> for AttributeNamespaceRepr {
+	fn from(namespace: RpcAttributeNamespace) -> Self {
+		match namespace {
+			RpcAttributeNamespace::Pallet => Self::Pallet(()),
+			RpcAttributeNamespace::CollectionOwner => Self::CollectionOwner(()),
+			RpcAttributeNamespace::ItemOwner => Self::ItemOwner(()),
+			RpcAttributeNamespace::Account(account) => Self::Account(account),
+		}
+	}
+}
+
+/// The way the namespace of an attribute is chosen when the attribute is queried.
+///
+/// Serialized as `{"exact":<namespace>}` or `"any"`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RpcNamespaceQuery {
+	/// The attribute is read from the given namespace only.
+	Exact(RpcAttributeNamespace),
+	/// The first attribute found, consulting the namespace precedence first and the delegated
+	/// accounts' namespaces afterwards.
+	Any,
+}
+
+/// The version of the NFTs runtime API exposed by the node.
+///
+/// All the methods are provided since the first version of the runtime API, the later ones will
+/// be gated by the `version`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcApiVersion {
+	/// The version of the runtime API.
+	pub version: u32,
+}
+
+impl RpcApiVersion {
+	/// Creates the description of the runtime API `version`.
+	pub fn new(version: u32) -> Self {
+		Self { version }
+	}
+}
+
+/// The item's core details as they are exposed over the RPC.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcItemInfo<AccountId> {
+	/// The owner of the item.
+	pub owner: AccountId,
+	/// The item's metadata, if it was set.
+	pub metadata: Option<Vec<u8>>,
+	/// The bitflags of the item's disabled settings.
+	pub settings: u64,
+	/// The number of attributes the item holds across all the namespaces.
+	pub attribute_count: u32,
+}
+
+impl<AccountId> From<ItemInfo<AccountId>> for RpcItemInfo<AccountId> {
+	fn from(info: ItemInfo<AccountId>) -> Self {
+		Self {
+			owner: info.owner,
+			metadata: info.metadata,
+			settings: info.settings,
+			attribute_count: info.attribute_count,
+		}
+	}
+}
+
+/// A change of the item's attribute as it is exposed over the RPC.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RpcAttributeChange {
+	/// The namespace of the attribute.
+	pub namespace: RpcAttributeNamespace,
+	/// The attribute's key.
+	pub key: Vec<u8>,
+	/// The new value of the attribute, `None` means the attribute was cleared.
+	pub value: Option<Vec<u8>>,
+}
+
+impl<AccountId: Encode> From<AttributeChange<AccountId>> for RpcAttributeChange {
+	fn from(change: AttributeChange<AccountId>) -> Self {
+		Self { namespace: change.namespace.into(), key: change.key, value: change.value }
+	}
+}
+
+/// The changes of the item's attributes made within a single block.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RpcItemAttributeChanges<BlockHash> {
+	/// The hash of the block the changes were made in.
+	pub block: BlockHash,
+	/// The changes in the order they were made.
+	pub changes: Vec<RpcAttributeChange>,
+}
+
+/// The difference between the item's attributes at two blocks.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RpcAttributeDiff {
+	/// The attributes present at the later block only, along with their values.
+	pub added: Vec<RpcAttributeChange>,
+	/// The attributes whose values differ, along with their values at the later block.
+	pub changed: Vec<RpcAttributeChange>,
+	/// The attributes present at the earlier block only.
+	pub removed: Vec<RpcAttributeChange>,
+}
+
+/// The change of the reserved deposit as it is exposed over the RPC.
+///
+/// Serialized as `{"reserve":..}` or `{"unreserve":..}`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RpcDepositDelta<Balance> {
+	/// The amount is going to be additionally reserved.
+	Reserve(Balance),
+	/// The amount is going to be returned.
+	Unreserve(Balance),
+}
+
+impl<Balance> From<DepositDelta<Balance>> for RpcDepositDelta<Balance> {
+	fn from(delta: DepositDelta<Balance>) -> Self {
+		match delta {
+			DepositDelta::Reserve(amount) => Self::Reserve(amount),
+			DepositDelta::Unreserve(amount) => Self::Unreserve(amount),
+		}
+	}
+}
+
+/// The simulated effect of setting an attribute as it is exposed over the RPC.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcSetAttributeEffect<AccountId, Balance> {
+	/// The change of the deposit reserved from the `deposit_account`.
+	pub deposit_delta: RpcDepositDelta<Balance>,
+	/// Whether the attribute doesn't exist yet and is going to be created.
+	pub will_create: bool,
+	/// The account the attribute's deposit is going to be reserved from.
+	pub deposit_account: AccountId,
+}
+
+impl<AccountId, Balance> From<SetAttributeEffect<AccountId, Balance>>
+	for RpcSetAttributeEffect<AccountId, Balance>
+{
+	fn from(effect: SetAttributeEffect<AccountId, Balance>) -> Self {
+		Self {
+			deposit_delta: effect.deposit_delta.into(),
+			will_create: effect.will_create,
+			deposit_account: effect.deposit_account,
+		}
+	}
+}
+
+/// The attribute's value decoded according to its `AttributeValueType`.
+///
+/// Serialized as `{"type":"Utf8","value":"hello"}`. Numbers are serialized as strings, since they
+/// might not fit into the JSON ones. Values that can't be decoded according to their type are
+/// returned as `Raw` hex-encoded bytes.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "value")]
+pub enum RpcDecodedAttributeValue {
+	/// Opaque bytes.
+	Raw(Bytes),
+	/// UTF-8 string.
+	Utf8(String),
+	/// `u128` number.
+	U128(String),
+	/// Boolean.
+	Bool(bool),
+	/// JSON document as a string.
+	Json(String),
+	/// The number of chunks the large attribute's value is split into.
+	ChunkManifest(u32),
+}
+
+impl RpcDecodedAttributeValue {
+	/// Decodes the `value` according to the `value_type`.
+	pub fn decode(value: Vec<u8>, value_type: AttributeValueType) -> Self {
+		let decoded = match value_type {
+			AttributeValueType::Raw => None,
+			AttributeValueType::Utf8 => String::from_utf8(value.clone()).ok().map(Self::Utf8),
+			AttributeValueType::Json => String::from_utf8(value.clone()).ok().map(Self::Json),
+			AttributeValueType::U128 =>
+				u128::decode_all(&mut &value[..]).ok().map(|n| Self::U128(n.to_string())),
+			AttributeValueType::Bool => bool::decode_all(&mut &value[..]).ok().map(Self::Bool),
+			AttributeValueType::ChunkManifest =>
+				u32::decode_all(&mut &value[..]).ok().map(Self::ChunkManifest),
+			AttributeValueType::Compressed => None,
+		};
+		decoded.unwrap_or_else(|| Self::Raw(value.into()))
+	}
+}
+
+/// NFTs RPC methods.
+#[rpc(client, server)]
+pub trait NftsApi<BlockHash, AccountId, CollectionId, ItemId, Balance, BlockNumber> {
+	/// Returns the owner of the `item` within the `collection`.
+	#[method(name = "nfts_owner")]
+	fn owner(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AccountId>>;
+
+	/// Returns the owner of the `collection`.
+	#[method(name = "nfts_collectionOwner")]
+	fn collection_owner(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AccountId>>;
+
+	/// Returns the value of the `item`'s attribute within the `namespace`.
+	#[method(name = "nfts_itemAttribute")]
+	fn item_attribute(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Vec<u8>>>;
+
+	/// Returns up to `limit` attributes of the `item` within the `namespace`.
+	///
+	/// The attributes are returned as key-value pairs starting right after the `start_key` cursor,
+	/// along with the cursor to pass as `start_key` to get the next page, if there are more
+	/// attributes left.
+	/// The `limit` can't exceed [`MAX_ATTRIBUTES_PAGE_SIZE`].
+	#[method(name = "nfts_itemAttributes")]
+	fn item_attributes(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		start_key: Option<AttributeCursor>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<(Vec<(Vec<u8>, Vec<u8>)>, Option<AttributeCursor>)>;
+
+	/// Returns the value of the `collection`'s attribute.
+	#[method(name = "nfts_collectionAttribute")]
+	fn collection_attribute(
+		&self,
+		collection: CollectionId,
+		key: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Vec<u8>>>;
+
+	/// Returns the metadata of the `item` within the `collection`.
+	#[method(name = "nfts_itemMetadata")]
+	fn item_metadata(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Vec<u8>>>;
+
+	/// Returns the metadata of the `collection`.
+	#[method(name = "nfts_collectionMetadata")]
+	fn collection_metadata(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Vec<u8>>>;
+
+	/// Returns the number of attributes stored within the `collection`.
+	///
+	/// `None` is returned when the collection doesn't exist.
+	#[method(name = "nfts_collectionAttributesCount")]
+	fn collection_attributes_count(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<u32>>;
+
+	/// Returns the owners of the `items` within the `collection`.
+	///
+	/// The result is positionally aligned with the provided `items`. The number of `items` can't
+	/// exceed [`MAX_OWNERS_PER_QUERY`].
+	#[method(name = "nfts_itemOwners")]
+	fn owners(
+		&self,
+		collection: CollectionId,
+		items: Vec<ItemId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Option<AccountId>>>;
+
+	/// Returns the namespace the `key` attribute of the `item` is resolved from.
+	///
+	/// `None` is returned when the attribute doesn't exist in any namespace.
+	#[method(name = "nfts_resolveAttributeNamespace")]
+	fn resolve_attribute_namespace(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		key: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RpcAttributeNamespace>>;
+
+	/// Returns the bitflags of the `item`'s disabled settings.
+	///
+	/// Each bit corresponds to an `ItemSetting`, e.g. a set `Transferable` bit means the item
+	/// can't be transferred.
+	#[method(name = "nfts_itemSettings")]
+	fn item_settings(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<u64>>;
+
+	/// Returns the value of the `item`'s attribute along with the type of that value.
+	///
+	/// The value type is returned as a SCALE-encoded `AttributeValueType`.
+	#[method(name = "nfts_itemTypedAttribute")]
+	fn typed_attribute(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(Vec<u8>, Bytes)>>;
+
+	/// Returns whether the item's attribute exists within the `namespace`.
+	#[method(name = "nfts_hasAttribute")]
+	fn has_attribute(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		key: Vec<u8>,
+		namespace: RpcAttributeNamespace,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+
+	/// Returns the maximum lengths of attributes' keys and values.
+	#[method(name = "nfts_attributeLimits")]
+	fn attribute_limits(&self, at: Option<BlockHash>) -> RpcResult<AttributeLimits>;
+
+	/// Returns every namespace the item's attribute is present in, along with the value stored
+	/// within that namespace.
+	#[method(name = "nfts_attributeNamespaces")]
+	fn attribute_namespaces(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		key: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(RpcAttributeNamespace, Vec<u8>)>>;
+
+	/// Returns up to `limit` items of the `collection` owned by the `account`.
+	///
+	/// The items are returned starting right after the `start` cursor, along with the cursor to
+	/// pass as `start` to get the next page, if there are more items left.
+	/// The `limit` can't exceed [`MAX_ACCOUNT_ITEMS_PAGE_SIZE`].
+	#[method(name = "nfts_accountItems")]
+	fn account_items(
+		&self,
+		account: AccountId,
+		collection: CollectionId,
+		start: Option<AttributeCursor>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<(Vec<ItemId>, Option<AttributeCursor>)>;
+
+	/// Returns whether the `account` is allowed to set attributes within the `namespace` of the
+	/// `collection` or of its `item`.
+	#[method(name = "nfts_canSetAttribute")]
+	fn can_set_attribute(
+		&self,
+		account: AccountId,
+		namespace: RpcAttributeNamespace,
+		collection: CollectionId,
+		item: Option<ItemId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+
+	/// Returns the accounts approved to set attributes of the `item`.
+	#[method(name = "nfts_itemAttributeApprovals")]
+	fn item_attribute_approvals(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<AccountId>>;
+
+	/// Returns up to `limit` attributes of the `item` within the `namespace` whose keys start with
+	/// the `key_prefix`.
+	///
+	/// Paginated the same way as `nfts_itemAttributes`. The `limit` can't exceed
+	/// [`MAX_ATTRIBUTES_PAGE_SIZE`].
+	#[method(name = "nfts_itemAttributesByPrefix")]
+	fn item_attributes_by_prefix(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key_prefix: Vec<u8>,
+		start_key: Option<AttributeCursor>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<(Vec<(Vec<u8>, Vec<u8>)>, Option<AttributeCursor>)>;
+
+	/// Returns the deposit required to store an attribute with the key and the value of the
+	/// provided lengths within the `namespace` of the `collection`.
+	#[method(name = "nfts_computeAttributeDeposit")]
+	fn compute_attribute_deposit(
+		&self,
+		collection: CollectionId,
+		namespace: RpcAttributeNamespace,
+		key_len: u32,
+		value_len: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Balance>>;
+
+	/// Returns the total deposit reserved from the owner of the `collection`.
+	#[method(name = "nfts_collectionOwnerDeposit")]
+	fn collection_owner_deposit(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Balance>>;
+
+	/// Returns the owner, metadata, settings and the number of attributes of the `item` within
+	/// the `collection` in a single call.
+	#[method(name = "nfts_itemInfo")]
+	fn item_info(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RpcItemInfo<AccountId>>>;
+
+	/// Subscribes to the changes of the `item`'s attributes within the `collection`.
+	///
+	/// A notification is sent for every new best block that sets or clears any of the item's
+	/// attributes.
+	#[subscription(
+		name = "nfts_subscribeItemAttributes" => "nfts_itemAttributeChanges",
+		unsubscribe = "nfts_unsubscribeItemAttributes",
+		item = RpcItemAttributeChanges<BlockHash>,
+	)]
+	fn subscribe_item_attributes(&self, collection: CollectionId, item: ItemId);
+
+	/// Returns the number of attributes stored within the `collection` in the namespaces of the
+	/// same kind as the `namespace`.
+	///
+	/// The account of the `Account` namespace is ignored, so the attributes of all the delegated
+	/// accounts are counted.
+	#[method(name = "nfts_namespaceAttributeCount")]
+	fn namespace_attribute_count(
+		&self,
+		collection: CollectionId,
+		namespace: RpcAttributeNamespace,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<u32>>;
+
+	/// Returns the value of the `item`'s attribute that was split into chunks because of its
+	/// size, reassembled from the chunks.
+	#[method(name = "nfts_largeItemAttribute")]
+	fn large_item_attribute(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Vec<u8>>>;
+
+	/// Simulates setting an attribute by the `origin` without persisting it, and returns the
+	/// effect it would have on the deposits.
+	#[method(name = "nfts_simulateSetAttribute")]
+	fn simulate_set_attribute(
+		&self,
+		origin: AccountId,
+		collection: CollectionId,
+		maybe_item: Option<ItemId>,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		value: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<RpcSetAttributeEffect<AccountId, Balance>>;
+
+	/// Returns the value of the `item`'s attribute within the `namespace`.
+	///
+	/// Unlike `nfts_itemAttribute`, fails with a distinct error if the `collection` or the `item`
+	/// doesn't exist.
+	#[method(name = "nfts_itemAttributeChecked")]
+	fn item_attribute_checked(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Vec<u8>>>;
+
+	/// Returns the final storage key of the `item`'s attribute within the `namespace`.
+	///
+	/// The key can be passed to `state_getReadProof` to verify the attribute's value.
+	#[method(name = "nfts_itemAttributeStorageKey")]
+	fn item_attribute_storage_key(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Bytes>>;
+
+	/// Returns the value of the `key` attribute within the `namespace` for each of the `items`.
+	///
+	/// The result is positionally aligned with the provided `items`. The number of `items` can't
+	/// exceed [`MAX_ITEMS_PER_ATTRIBUTE_QUERY`].
+	#[method(name = "nfts_itemsAttribute")]
+	fn items_attribute(
+		&self,
+		collection: CollectionId,
+		items: Vec<ItemId>,
+		key: Vec<u8>,
+		namespace: RpcAttributeNamespace,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Option<Vec<u8>>>>;
+
+	/// Returns whether the attributes set by the collection's owner for the `item` are locked.
+	#[method(name = "nfts_itemAttributesLocked")]
+	fn item_attributes_locked(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+
+	/// Returns the accounts approved to set the `item`'s attributes along with the number of
+	/// attributes each of them has set.
+	#[method(name = "nfts_itemAttributeDelegates")]
+	fn item_attribute_delegates(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, u32)>>;
+
+	/// Returns the pointer to the item's off-chain metadata.
+	///
+	/// That is the value of the `METADATA_URI_KEY` attribute resolved according to the namespace
+	/// precedence, or the item's on-chain metadata if there is no such attribute. `None` is
+	/// returned when neither exists.
+	#[method(name = "nfts_itemMetadataUri")]
+	fn item_metadata_uri(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Bytes>>;
+
+	/// Returns up to `limit` attributes of the `collection` itself.
+	///
+	/// Works the same way as `nfts_itemAttributes` does for the attributes set within the
+	/// `CollectionOwner` namespace. The `limit` can't exceed [`MAX_ATTRIBUTES_PAGE_SIZE`].
+	#[method(name = "nfts_collectionAttributes")]
+	fn collection_attributes(
+		&self,
+		collection: CollectionId,
+		start_key: Option<AttributeCursor>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<(Vec<(Vec<u8>, Vec<u8>)>, Option<AttributeCursor>)>;
+
+	/// Returns the block at which the `item`'s attribute within the `namespace` was last set.
+	///
+	/// The attributes that haven't been rewritten since the tracking was introduced are reported
+	/// as set at the block zero. `None` is returned when the attribute doesn't exist.
+	#[method(name = "nfts_itemAttributeLastSet")]
+	fn attribute_last_set(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<BlockNumber>>;
+
+	/// Returns whether the `account` is approved to set the `item`'s attributes within its own
+	/// `Account` namespace.
+	#[method(name = "nfts_isItemAttributeDelegate")]
+	fn is_item_attribute_delegate(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+
+	/// Returns the value of the `item`'s attribute decoded according to its value type.
+	///
+	/// Serialized as `{"type":"Utf8","value":"hello"}`, `{"type":"U128","value":"42"}` and so on,
+	/// see [`RpcDecodedAttributeValue`].
+	#[method(name = "nfts_itemAttributeDecoded")]
+	fn item_attribute_decoded(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		key: Vec<u8>,
+		namespace: RpcAttributeNamespace,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RpcDecodedAttributeValue>>;
+
+	/// Returns the namespaces consulted when the `key` attribute of the `item` is resolved, in
+	/// the order they are consulted.
+	#[method(name = "nfts_namespacePrecedenceOrder")]
+	fn namespace_precedence_order(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		key: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<RpcAttributeNamespace>>;
+
+	/// Returns whether the `item` holds any attribute within any namespace.
+	#[method(name = "nfts_itemHasAnyAttribute")]
+	fn item_has_any_attribute(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+
+	/// Returns the sum of the deposits of the `item`'s attributes within all the namespaces, i.e.
+	/// the amount refunded once all of them are cleared.
+	///
+	/// NOTE: the runtime iterates over all the item's attributes to compute it.
+	#[method(name = "nfts_itemTotalAttributeDeposit")]
+	fn item_total_attribute_deposit(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Balance>;
+
+	/// Returns the version of the NFTs runtime API, or `None` if the runtime doesn't expose the
+	/// API.
+	///
+	/// Lets the clients discover what's supported without trial-calling the methods.
+	#[method(name = "nfts_apiVersion")]
+	fn api_version(&self, at: Option<BlockHash>) -> RpcResult<Option<RpcApiVersion>>;
+
+	/// Returns up to `limit` values of the attribute stored under `key` within the `namespace`
+	/// across all the items of the `collection`, along with the items holding them.
+	///
+	/// The values are returned starting right after the `start` cursor, along with the cursor to
+	/// pass as `start` to get the next page, if there are more values left. The items that don't
+	/// hold the attribute are skipped. The `limit` can't exceed [`MAX_ATTRIBUTES_PAGE_SIZE`].
+	#[method(name = "nfts_collectionAttributeByKey")]
+	fn collection_attribute_by_key(
+		&self,
+		collection: CollectionId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		start: Option<AttributeCursor>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<(Vec<(ItemId, Vec<u8>)>, Option<AttributeCursor>)>;
+
+	/// Returns the value of the `item`'s attribute stored under `key` resolved according to the
+	/// namespace precedence, along with the namespace it was resolved from.
+	///
+	/// Saves a round-trip compared to `nfts_resolveAttributeNamespace` followed by
+	/// `nfts_itemAttribute`.
+	#[method(name = "nfts_itemAttributeResolved")]
+	fn item_attribute_resolved(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		key: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(Vec<u8>, RpcAttributeNamespace)>>;
+
+	/// Returns the royalty beneficiary and the royalty of the item.
+	///
+	/// That is the `(AccountId, Permill)` SCALE-encoded within the `ROYALTY_KEY` attribute
+	/// resolved according to the namespace precedence. `None` is returned when there is no such
+	/// attribute or its value can't be decoded.
+	#[method(name = "nfts_itemRoyalty")]
+	fn item_royalty(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(AccountId, Perbill)>>;
+
+	/// Returns the value of the `item`'s attribute stored under `key` along with the namespace
+	/// it was read from.
+	///
+	/// Unlike `nfts_itemAttribute`, the namespace is chosen according to the `query`:
+	/// - `null`: the namespace precedence, the same as `nfts_itemAttributeResolved`;
+	/// - `{"exact":<namespace>}`: the given namespace only;
+	/// - `"any"`: the namespace precedence, followed by the delegated accounts' namespaces.
+	#[method(name = "nfts_itemAttributeQuery")]
+	fn item_attribute_query(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		query: Option<RpcNamespaceQuery>,
+		key: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(Vec<u8>, RpcAttributeNamespace)>>;
+
+	/// Returns the difference between the `item`'s attributes at the `from` and the `to` blocks.
+	///
+	/// All the namespaces are compared, including the ones of the accounts delegated at either
+	/// of the blocks. Fails when the item holds more than [`MAX_ATTRIBUTE_DIFF_KEYS`] attributes
+	/// at either of the blocks.
+	#[method(name = "nfts_itemAttributeDiff")]
+	fn item_attribute_diff(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		from: BlockHash,
+		to: BlockHash,
+	) -> RpcResult<RpcAttributeDiff>;
+
+	/// Returns the keys of the `item`'s attributes nested under the `path` across all the
+	/// namespaces, along with the namespace each of them is stored within.
+	///
+	/// The hierarchical keys consist of the segments joined with the
+	/// `well_known_keys::PATH_SEPARATOR`, e.g. the `stats/strength` key is nested under the
+	/// `stats` path, while an empty `path` matches all the keys. Fails when there are more than
+	/// [`MAX_ATTRIBUTE_DIFF_KEYS`] such keys.
+	#[method(name = "nfts_itemAttributesUnderPath")]
+	fn item_attributes_under_path(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		path: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(RpcAttributeNamespace, Vec<u8>)>>;
+
+	/// Returns the value of the `collection`'s attribute stored under `key` within the
+	/// `CollectionOwner` namespace, along with the part of the collection owner's deposit it
+	/// accounts for.
+	///
+	/// That is the attribute's deposit if the collection's owner paid it, and zero if it was
+	/// paid by another account.
+	#[method(name = "nfts_collectionAttributeWithDeposit")]
+	fn collection_attribute_with_deposit(
+		&self,
+		collection: CollectionId,
+		key: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(Vec<u8>, Balance)>>;
+}
+
+/// Provides RPC methods to query the state of the NFTs pallet.
+pub struct Nfts<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	/// Executor to spawn the subscriptions.
+	executor: SubscriptionTaskExecutor,
+	/// The max size of the attribute keys and values returned within a single response.
+	max_response_size: usize,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Nfts<C, P> {
+	/// Creates a new instance of the Nfts Rpc helper.
+	///
+	/// The calls returning more than `max_response_size` bytes of the attribute keys and values
+	/// fail, see [`DEFAULT_MAX_RESPONSE_SIZE`].
+	pub fn new(
+		client: Arc<C>,
+		executor: SubscriptionTaskExecutor,
+		max_response_size: usize,
+	) -> Self {
+		Self { client, executor, max_response_size, _marker: Default::default() }
+	}
+
+	/// Fails when the `size` of the attribute keys and values to return exceeds the limit.
+	fn ensure_response_size(&self, size: usize) -> Result<(), CallError> {
+		if size > self.max_response_size {
+			return Err(CallError::Custom(ErrorObject::owned(
+				Error::ResponseTooLarge.into(),
+				"The response is too large.",
+				Some(format!("{} bytes exceed the limit of {}", size, self.max_response_size)),
+			)))
+		}
+		Ok(())
+	}
+}
+
+impl<C, Block> Nfts<C, Block>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	/// Returns the hash of the block to query the state at, defaulting to the best block.
+	///
+	/// Fails when the node doesn't know the requested block, e.g. its state was pruned.
+	fn block_hash(&self, at: Option<Block::Hash>) -> Result<Block::Hash, CallError> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		match self.client.header(at_hash) {
+			Ok(Some(_)) => Ok(at_hash),
+			Ok(None) => Err(CallError::Custom(ErrorObject::owned(
+				Error::StateUnavailable.into(),
+				"State unavailable at requested block.",
+				Some(format!("{:?}", at_hash)),
+			))),
+			Err(e) => Err(runtime_error(e, "Unable to query the requested block.")),
+		}
+	}
+
+	/// Returns all the `item`'s attributes whose keys start with the `key_prefix` at the `at`
+	/// block, keyed by the SCALE-encoded namespace and the attribute's key.
+	///
+	/// Fails when there are more than [`MAX_ATTRIBUTE_DIFF_KEYS`] such attributes.
+	fn all_item_attributes<AccountId, CollectionId, ItemId, Balance>(
+		&self,
+		collection: &CollectionId,
+		item: &ItemId,
+		key_prefix: &[u8],
+		at: Block::Hash,
+	) -> Result<BTreeMap<(Vec<u8>, Vec<u8>), (RpcAttributeNamespace, Vec<u8>)>, CallError>
+	where
+		C: ProvideRuntimeApi<Block>,
+		C::Api: NftsRuntimeApi<Block, AccountId, CollectionId, ItemId, Balance>,
+		AccountId: Codec,
+		CollectionId: Codec + Clone,
+		ItemId: Codec + Clone,
+		Balance: Codec,
+	{
+		let api = self.client.runtime_api();
+		let accounts = api
+			.item_attribute_accounts(at, collection.clone(), item.clone())
+			.map_err(|e| runtime_error(e, "Unable to query item attribute accounts."))?;
+		let namespaces = [
+			RpcAttributeNamespace::Pallet,
+			RpcAttributeNamespace::CollectionOwner,
+			RpcAttributeNamespace::ItemOwner,
+		]
+		.into_iter()
+		.chain(accounts.into_iter().map(|account| AttributeNamespace::Account(account).into()));
+
+		let mut attributes = BTreeMap::new();
+		for rpc_namespace in namespaces {
+			let mut start_key = None;
+			loop {
+				let namespace = decode_namespace::<AccountId>(rpc_namespace.clone())?;
+				let encoded_namespace = namespace.encode();
+				let (page, next_key) = api
+					.item_attributes_filtered(
+						at,
+						collection.clone(),
+						item.clone(),
+						namespace,
+						key_prefix.to_vec(),
+						start_key,
+						MAX_ATTRIBUTES_PAGE_SIZE,
+					)
+					.map_err(|e| runtime_error(e, "Unable to query item attributes."))?;
+				for (key, value) in page {
+					attributes
+						.insert((encoded_namespace.clone(), key), (rpc_namespace.clone(), value));
+				}
+				if attributes.len() > MAX_ATTRIBUTE_DIFF_KEYS as usize {
+					return Err(invalid_params(format!(
+						"there are more than {} attributes",
+						MAX_ATTRIBUTE_DIFF_KEYS
+					)))
+				}
+				match next_key {
+					Some(next_key) => start_key = Some(next_key),
+					None => break,
+				}
+			}
+		}
+		Ok(attributes)
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The requested collection doesn't exist.
+	UnknownCollection,
+	/// The requested item doesn't exist.
+	UnknownItem,
+	/// The provided data was not decodable.
+	DecodeError,
+	/// The state at the requested block is not available.
+	StateUnavailable,
+	/// The simulated call would fail.
+	DispatchFailed,
+	/// The response exceeds the max size.
+	ResponseTooLarge,
+	/// The provided attribute namespace was not decodable, the error data holds its encoding.
+	InvalidNamespace,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::UnknownCollection => 2,
+			Error::UnknownItem => 3,
+			Error::DecodeError => 4,
+			Error::StateUnavailable => 5,
+			Error::DispatchFailed => 6,
+			Error::ResponseTooLarge => 7,
+			Error::InvalidNamespace => 8,
+		}
+	}
+}
+
+fn runtime_error(error: impl ToString, desc: &'static str) -> CallError {
+	CallError::Custom(ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string())))
+}
+
+fn dispatch_error(error: impl std::fmt::Debug) -> CallError {
+	CallError::Custom(ErrorObject::owned(
+		Error::DispatchFailed.into(),
+		"The call would fail.",
+		Some(format!("{:?}", error)),
+	))
+}
+
+/// Returns the total size of the attributes' keys and values.
+fn attributes_size(attributes: &[(Vec<u8>, Vec<u8>)]) -> usize {
+	attributes.iter().map(|(key, value)| key.len() + value.len()).sum()
+}
+
+fn unknown_collection() -> CallError {
+	CallError::Custom(ErrorObject::owned(
+		Error::UnknownCollection.into(),
+		"Unknown collection.",
+		None::<()>,
+	))
+}
+
+fn unknown_item() -> CallError {
+	CallError::Custom(ErrorObject::owned(Error::UnknownItem.into(), "Unknown item.", None::<()>))
+}
+
+fn invalid_params(message: String) -> CallError {
+	CallError::Custom(ErrorObject::owned(ErrorCode::InvalidParams.code(), message, None::<()>))
+}
+
+fn decode_namespace<AccountId: Codec>(
+	namespace: RpcAttributeNamespace,
+) -> Result<AttributeNamespace<AccountId>, CallError> {
+	// only the account can be malformed, it is passed back to simplify debugging the encoding
+	let encoded = match &namespace {
+		RpcAttributeNamespace::Account(account) => Some(account.clone()),
+		_ => None,
+	};
+	namespace.try_into_namespace().map_err(|e| {
+		CallError::Custom(ErrorObject::owned(
+			Error::InvalidNamespace.into(),
+			format!("Unable to decode attribute namespace: {}", e),
+			encoded,
+		))
+	})
+}
+
+impl<C, Block, AccountId, CollectionId, ItemId, Balance>
+	NftsApiServer<
+		<Block as BlockT>::Hash,
+		AccountId,
+		CollectionId,
+		ItemId,
+		Balance,
+		NumberFor<Block>,
+	> for Nfts<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ BlockchainEvents<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+	C::Api: NftsRuntimeApi<Block, AccountId, CollectionId, ItemId, Balance>,
+	AccountId: Codec + Send + Sync + 'static,
+	CollectionId: Codec + Clone + Send + Sync + 'static,
+	ItemId: Codec + Clone + Send + Sync + 'static,
+	Balance: Codec + Send + Sync + 'static,
+{
+	fn owner(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<AccountId>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		api.owner(at_hash, collection, item)
+			.map_err(|e| runtime_error(e, "Unable to query item owner.").into())
+	}
+
+	fn collection_owner(
+		&self,
+		collection: CollectionId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<AccountId>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		api.collection_owner(at_hash, collection)
+			.map_err(|e| runtime_error(e, "Unable to query collection owner.").into())
+	}
+
+	fn item_attribute(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Vec<u8>>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let namespace = decode_namespace(namespace)?;
+
+		let value = api
+			.attribute(at_hash, collection, item, namespace, key)
+			.map_err(|e| runtime_error(e, "Unable to query item attribute."))?;
+		self.ensure_response_size(value.as_ref().map_or(0, Vec::len))?;
+		Ok(value)
+	}
+
+	fn item_attributes(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		start_key: Option<AttributeCursor>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<(Vec<(Vec<u8>, Vec<u8>)>, Option<AttributeCursor>)> {
+		if limit > MAX_ATTRIBUTES_PAGE_SIZE {
+			return Err(
+				invalid_params(format!("limit can't exceed {}", MAX_ATTRIBUTES_PAGE_SIZE)).into()
+			)
+		}
+
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		if api
+			.collection_owner(at_hash, collection.clone())
+			.map_err(|e| runtime_error(e, "Unable to query collection owner."))?
+			.is_none()
+		{
+			return Err(unknown_collection().into())
+		}
+
+		let namespace = decode_namespace(namespace)?;
+
+		let (attributes, next_key) = api
+			.item_attributes(at_hash, collection, item, namespace, start_key.map(Into::into), limit)
+			.map_err(|e| runtime_error(e, "Unable to query item attributes."))?;
+		self.ensure_response_size(attributes_size(&attributes))?;
+		Ok((attributes, next_key.map(Into::into)))
+	}
+
+	fn collection_attribute(
+		&self,
+		collection: CollectionId,
+		key: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Vec<u8>>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let value = api
+			.collection_attribute(at_hash, collection, key)
+			.map_err(|e| runtime_error(e, "Unable to query collection attribute."))?;
+		self.ensure_response_size(value.as_ref().map_or(0, Vec::len))?;
+		Ok(value)
+	}
+
+	fn item_metadata(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Vec<u8>>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let metadata = api
+			.item_metadata(at_hash, collection, item)
+			.map_err(|e| runtime_error(e, "Unable to query item metadata."))?;
+		self.ensure_response_size(metadata.as_ref().map_or(0, Vec::len))?;
+		Ok(metadata)
+	}
+
+	fn collection_metadata(
+		&self,
+		collection: CollectionId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Vec<u8>>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let metadata = api
+			.collection_metadata(at_hash, collection)
+			.map_err(|e| runtime_error(e, "Unable to query collection metadata."))?;
+		self.ensure_response_size(metadata.as_ref().map_or(0, Vec::len))?;
+		Ok(metadata)
+	}
+
+	fn collection_attributes_count(
+		&self,
+		collection: CollectionId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<u32>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		api.collection_attributes_count(at_hash, collection)
+			.map_err(|e| runtime_error(e, "Unable to query collection attributes count.").into())
+	}
+
+	fn owners(
+		&self,
+		collection: CollectionId,
+		items: Vec<ItemId>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<Option<AccountId>>> {
+		if items.len() > MAX_OWNERS_PER_QUERY as usize {
+			return Err(invalid_params(format!(
+				"number of items can't exceed {}",
+				MAX_OWNERS_PER_QUERY
+			))
+			.into())
+		}
+
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		api.owners(at_hash, collection, items)
+			.map_err(|e| runtime_error(e, "Unable to query item owners.").into())
+	}
+
+	fn resolve_attribute_namespace(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		key: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<RpcAttributeNamespace>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let namespace = api
+			.resolve_attribute_namespace(at_hash, collection, item, key)
+			.map_err(|e| runtime_error(e, "Unable to resolve attribute namespace."))?;
+		Ok(namespace.map(Into::into))
+	}
+
+	fn item_settings(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<u64>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		api.item_settings(at_hash, collection, item)
+			.map_err(|e| runtime_error(e, "Unable to query item settings.").into())
+	}
+
+	fn typed_attribute(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<(Vec<u8>, Bytes)>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let namespace = decode_namespace(namespace)?;
+
+		let attribute = api
+			.typed_attribute(at_hash, collection, item, namespace, key)
+			.map_err(|e| runtime_error(e, "Unable to query item attribute."))?;
+		self.ensure_response_size(attribute.as_ref().map_or(0, |(value, _)| value.len()))?;
+		Ok(attribute.map(|(value, value_type)| (value, value_type.encode().into())))
+	}
+
+	fn has_attribute(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		key: Vec<u8>,
+		namespace: RpcAttributeNamespace,
+		at: Option<Block::Hash>,
+	) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		if api
+			.collection_owner(at_hash, collection.clone())
+			.map_err(|e| runtime_error(e, "Unable to query collection owner."))?
+			.is_none()
+		{
+			return Err(unknown_collection().into())
+		}
+
+		let namespace = decode_namespace(namespace)?;
+
+		api.has_attribute(at_hash, collection, item, key, namespace)
+			.map_err(|e| runtime_error(e, "Unable to query item attribute.").into())
+	}
+
+	fn attribute_limits(&self, at: Option<Block::Hash>) -> RpcResult<AttributeLimits> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let key_limit = api
+			.attribute_key_limit(at_hash)
+			.map_err(|e| runtime_error(e, "Unable to query attribute limits."))?;
+		let value_limit = api
+			.attribute_value_limit(at_hash)
+			.map_err(|e| runtime_error(e, "Unable to query attribute limits."))?;
+		Ok(AttributeLimits { key_limit, value_limit })
+	}
+
+	fn attribute_namespaces(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		key: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(RpcAttributeNamespace, Vec<u8>)>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		if api
+			.collection_owner(at_hash, collection.clone())
+			.map_err(|e| runtime_error(e, "Unable to query collection owner."))?
+			.is_none()
+		{
+			return Err(unknown_collection().into())
+		}
+
+		let attributes = api
+			.attribute_namespaces(at_hash, collection, item, key)
+			.map_err(|e| runtime_error(e, "Unable to query attribute namespaces."))?;
+		self.ensure_response_size(attributes.iter().map(|(_, value)| value.len()).sum())?;
+		Ok(attributes
+			.into_iter()
+			.map(|(namespace, value)| (namespace.into(), value))
+			.collect())
+	}
+
+	fn account_items(
+		&self,
+		account: AccountId,
+		collection: CollectionId,
+		start: Option<AttributeCursor>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<(Vec<ItemId>, Option<AttributeCursor>)> {
+		if limit > MAX_ACCOUNT_ITEMS_PAGE_SIZE {
+			return Err(invalid_params(format!(
+				"limit can't exceed {}",
+				MAX_ACCOUNT_ITEMS_PAGE_SIZE
+			))
+			.into())
+		}
+
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		if api
+			.collection_owner(at_hash, collection.clone())
+			.map_err(|e| runtime_error(e, "Unable to query collection owner."))?
+			.is_none()
+		{
+			return Err(unknown_collection().into())
+		}
+
+		let start = start
+			.map(|cursor| ItemId::decode_all(&mut &cursor.0[..]))
+			.transpose()
+			.map_err(|_| invalid_params("invalid cursor".into()))?;
+		let (items, next) = api
+			.account_items(at_hash, account, collection, start, limit)
+			.map_err(|e| runtime_error(e, "Unable to query account items."))?;
+		Ok((items, next.map(|item| item.encode().into())))
+	}
+
+	fn can_set_attribute(
+		&self,
+		account: AccountId,
+		namespace: RpcAttributeNamespace,
+		collection: CollectionId,
+		item: Option<ItemId>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		if api
+			.collection_owner(at_hash, collection.clone())
+			.map_err(|e| runtime_error(e, "Unable to query collection owner."))?
+			.is_none()
+		{
+			return Err(unknown_collection().into())
+		}
+
+		let namespace = decode_namespace(namespace)?;
+
+		api.can_set_attribute(at_hash, account, namespace, collection, item)
+			.map_err(|e| runtime_error(e, "Unable to query attribute permissions.").into())
+	}
+
+	fn item_attribute_approvals(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<AccountId>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		if api
+			.owner(at_hash, collection.clone(), item.clone())
+			.map_err(|e| runtime_error(e, "Unable to query item owner."))?
+			.is_none()
+		{
+			return Err(unknown_item().into())
+		}
+
+		api.item_attribute_approvals(at_hash, collection, item)
+			.map_err(|e| runtime_error(e, "Unable to query item attribute approvals.").into())
+	}
+
+	fn item_attributes_by_prefix(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key_prefix: Vec<u8>,
+		start_key: Option<AttributeCursor>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<(Vec<(Vec<u8>, Vec<u8>)>, Option<AttributeCursor>)> {
+		if limit > MAX_ATTRIBUTES_PAGE_SIZE {
+			return Err(
+				invalid_params(format!("limit can't exceed {}", MAX_ATTRIBUTES_PAGE_SIZE)).into()
+			)
+		}
+
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		if api
+			.collection_owner(at_hash, collection.clone())
+			.map_err(|e| runtime_error(e, "Unable to query collection owner."))?
+			.is_none()
+		{
+			return Err(unknown_collection().into())
+		}
+
+		let namespace = decode_namespace(namespace)?;
+
+		let (attributes, next_key) = api
+			.item_attributes_filtered(
+				at_hash,
+				collection,
+				item,
+				namespace,
+				key_prefix,
+				start_key.map(Into::into),
+				limit,
+			)
+			.map_err(|e| runtime_error(e, "Unable to query item attributes."))?;
+		self.ensure_response_size(attributes_size(&attributes))?;
+		Ok((attributes, next_key.map(Into::into)))
+	}
+
+	fn compute_attribute_deposit(
+		&self,
+		collection: CollectionId,
+		namespace: RpcAttributeNamespace,
+		key_len: u32,
+		value_len: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Balance>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let namespace = decode_namespace(namespace)?;
+
+		api.compute_attribute_deposit(at_hash, collection, namespace, key_len, value_len)
+			.map_err(|e| runtime_error(e, "Unable to compute attribute deposit.").into())
+	}
+
+	fn collection_owner_deposit(
+		&self,
+		collection: CollectionId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Balance>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		api.collection_owner_deposit(at_hash, collection)
+			.map_err(|e| runtime_error(e, "Unable to query collection owner deposit.").into())
+	}
+
+	fn item_info(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<RpcItemInfo<AccountId>>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let info = api
+			.item_info(at_hash, collection, item)
+			.map_err(|e| runtime_error(e, "Unable to query item info."))?;
+		Ok(info.map(Into::into))
+	}
+
+	fn subscribe_item_attributes(
+		&self,
+		mut sink: SubscriptionSink,
+		collection: CollectionId,
+		item: ItemId,
+	) -> SubscriptionResult {
+		let client = self.client.clone();
+		let stream = self
+			.client
+			.import_notification_stream()
+			.filter(|notification| future::ready(notification.is_new_best))
+			.filter_map(move |notification| {
+				let changes = client
+					.runtime_api()
+					.item_attribute_changes(notification.hash, collection.clone(), item.clone())
+					.ok()
+					.filter(|changes| !changes.is_empty())
+					.map(|changes| RpcItemAttributeChanges {
+						block: notification.hash,
+						changes: changes.into_iter().map(Into::into).collect(),
+					});
+				future::ready(changes)
+			});
+
+		// the import notifications stream gets dropped once the subscription is closed
+		let fut = async move {
+			sink.pipe_from_stream(stream).await;
+		};
+
+		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
+		Ok(())
+	}
+
+	fn namespace_attribute_count(
+		&self,
+		collection: CollectionId,
+		namespace: RpcAttributeNamespace,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<u32>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		api.namespace_attribute_count(at_hash, collection, namespace.discriminant())
+			.map_err(|e| runtime_error(e, "Unable to query namespace attribute count.").into())
+	}
+
+	fn large_item_attribute(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Vec<u8>>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let namespace = decode_namespace(namespace)?;
+
+		let value = api
+			.large_attribute(at_hash, collection, item, namespace, key)
+			.map_err(|e| runtime_error(e, "Unable to query large item attribute."))?;
+		self.ensure_response_size(value.as_ref().map_or(0, Vec::len))?;
+		Ok(value)
+	}
+
+	fn simulate_set_attribute(
+		&self,
+		origin: AccountId,
+		collection: CollectionId,
+		maybe_item: Option<ItemId>,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		value: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<RpcSetAttributeEffect<AccountId, Balance>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let namespace = decode_namespace(namespace)?;
+
+		api.simulate_set_attribute(at_hash, origin, collection, maybe_item, namespace, key, value)
+			.map_err(|e| runtime_error(e, "Unable to simulate setting attribute."))?
+			.map(Into::into)
+			.map_err(|e| dispatch_error(e).into())
+	}
+
+	fn item_attribute_checked(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Vec<u8>>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let namespace = decode_namespace(namespace)?;
+
+		let value = api
+			.attribute_checked(at_hash, collection, item, namespace, key)
+			.map_err(|e| runtime_error(e, "Unable to query item attribute."))?
+			.map_err(|e| match e {
+				ItemQueryError::UnknownCollection => unknown_collection(),
+				ItemQueryError::UnknownItem => unknown_item(),
+			})?;
+		self.ensure_response_size(value.as_ref().map_or(0, Vec::len))?;
+		Ok(value)
+	}
+
+	fn item_attribute_storage_key(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Bytes>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let namespace = decode_namespace(namespace)?;
+
+		api.item_attribute_storage_key(at_hash, collection, item, namespace, key)
+			.map(|key| key.map(Into::into))
+			.map_err(|e| runtime_error(e, "Unable to query attribute storage key.").into())
+	}
+
+	fn items_attribute(
+		&self,
+		collection: CollectionId,
+		items: Vec<ItemId>,
+		key: Vec<u8>,
+		namespace: RpcAttributeNamespace,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<Option<Vec<u8>>>> {
+		if items.len() > MAX_ITEMS_PER_ATTRIBUTE_QUERY as usize {
+			return Err(invalid_params(format!(
+				"number of items can't exceed {}",
+				MAX_ITEMS_PER_ATTRIBUTE_QUERY
+			))
+			.into())
+		}
+
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let namespace = decode_namespace(namespace)?;
+
+		let values = api
+			.items_attribute(at_hash, collection, items, namespace, key)
+			.map_err(|e| runtime_error(e, "Unable to query items attribute."))?;
+		self.ensure_response_size(values.iter().flatten().map(Vec::len).sum())?;
+		Ok(values)
+	}
+
+	fn item_attributes_locked(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		api.item_attributes_locked(at_hash, collection, item)
+			.map_err(|e| runtime_error(e, "Unable to query item attributes lock.").into())
+	}
+
+	fn item_attribute_delegates(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(AccountId, u32)>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		api.item_attribute_delegates(at_hash, collection, item)
+			.map_err(|e| runtime_error(e, "Unable to query item attribute delegates.").into())
+	}
+
+	fn item_metadata_uri(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Bytes>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let uri = api
+			.item_metadata_uri(at_hash, collection, item, METADATA_URI_KEY.to_vec())
+			.map_err(|e| runtime_error(e, "Unable to query item metadata URI."))?;
+		self.ensure_response_size(uri.as_ref().map_or(0, Vec::len))?;
+		Ok(uri.map(Into::into))
+	}
+
+	fn collection_attributes(
+		&self,
+		collection: CollectionId,
+		start_key: Option<AttributeCursor>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<(Vec<(Vec<u8>, Vec<u8>)>, Option<AttributeCursor>)> {
+		if limit > MAX_ATTRIBUTES_PAGE_SIZE {
+			return Err(
+				invalid_params(format!("limit can't exceed {}", MAX_ATTRIBUTES_PAGE_SIZE)).into()
+			)
+		}
+
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		if api
+			.collection_owner(at_hash, collection.clone())
+			.map_err(|e| runtime_error(e, "Unable to query collection owner."))?
+			.is_none()
+		{
+			return Err(unknown_collection().into())
+		}
+
+		let (attributes, next_key) = api
+			.collection_attributes(at_hash, collection, start_key.map(Into::into), limit)
+			.map_err(|e| runtime_error(e, "Unable to query collection attributes."))?;
+		self.ensure_response_size(attributes_size(&attributes))?;
+		Ok((attributes, next_key.map(Into::into)))
+	}
+
+	fn attribute_last_set(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<NumberFor<Block>>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let namespace = decode_namespace(namespace)?;
+
+		api.attribute_last_set(at_hash, collection, item, namespace, key)
+			.map_err(|e| runtime_error(e, "Unable to query attribute last set block.").into())
+	}
+
+	fn is_item_attribute_delegate(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		account: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		api.is_item_attribute_delegate(at_hash, collection, item, account)
+			.map_err(|e| runtime_error(e, "Unable to query item attribute delegate.").into())
+	}
+
+	fn item_attribute_decoded(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		key: Vec<u8>,
+		namespace: RpcAttributeNamespace,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<RpcDecodedAttributeValue>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let namespace = decode_namespace(namespace)?;
+
+		let attribute = api
+			.typed_attribute(at_hash, collection, item, namespace, key)
+			.map_err(|e| runtime_error(e, "Unable to query item attribute."))?;
+		self.ensure_response_size(attribute.as_ref().map_or(0, |(value, _)| value.len()))?;
+		Ok(attribute.map(|(value, value_type)| RpcDecodedAttributeValue::decode(value, value_type)))
+	}
+
+	fn namespace_precedence_order(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		key: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<RpcAttributeNamespace>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let order = api
+			.namespace_precedence_order(at_hash, collection, item, key)
+			.map_err(|e| runtime_error(e, "Unable to query namespace precedence order."))?;
+		Ok(order.into_iter().map(Into::into).collect())
+	}
+
+	fn item_has_any_attribute(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		api.item_has_any_attribute(at_hash, collection, item)
+			.map_err(|e| runtime_error(e, "Unable to query item attributes.").into())
+	}
+
+	fn item_total_attribute_deposit(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Balance> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		api.item_total_attribute_deposit(at_hash, collection, item)
+			.map_err(|e| runtime_error(e, "Unable to query item attribute deposit.").into())
+	}
+
+	fn api_version(&self, at: Option<Block::Hash>) -> RpcResult<Option<RpcApiVersion>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let version = api
+			.api_version::<dyn NftsRuntimeApi<Block, AccountId, CollectionId, ItemId, Balance>>(
+				at_hash,
+			)
+			.map_err(|e| runtime_error(e, "Unable to query runtime api version."))?;
+		Ok(version.map(RpcApiVersion::new))
+	}
+
+	fn collection_attribute_by_key(
+		&self,
+		collection: CollectionId,
+		namespace: RpcAttributeNamespace,
+		key: Vec<u8>,
+		start: Option<AttributeCursor>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<(Vec<(ItemId, Vec<u8>)>, Option<AttributeCursor>)> {
+		if limit > MAX_ATTRIBUTES_PAGE_SIZE {
+			return Err(
+				invalid_params(format!("limit can't exceed {}", MAX_ATTRIBUTES_PAGE_SIZE)).into()
+			)
+		}
+
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		if api
+			.collection_owner(at_hash, collection.clone())
+			.map_err(|e| runtime_error(e, "Unable to query collection owner."))?
+			.is_none()
+		{
+			return Err(unknown_collection().into())
+		}
+
+		let namespace = decode_namespace(namespace)?;
+		let start = start
+			.map(|cursor| ItemId::decode_all(&mut &cursor.0[..]))
+			.transpose()
+			.map_err(|_| invalid_params("invalid cursor".into()))?;
+		let (values, next) = api
+			.collection_namespace_attributes(at_hash, collection, namespace, key, start, limit)
+			.map_err(|e| runtime_error(e, "Unable to query collection attributes."))?;
+		self.ensure_response_size(values.iter().map(|(_, value)| value.len()).sum())?;
+		Ok((values, next.map(|item| item.encode().into())))
+	}
+
+	fn item_attribute_resolved(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		key: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<(Vec<u8>, RpcAttributeNamespace)>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let resolved = api
+			.item_attribute_resolved(at_hash, collection, item, key)
+			.map_err(|e| runtime_error(e, "Unable to resolve item attribute."))?;
+		self.ensure_response_size(resolved.as_ref().map_or(0, |(value, _)| value.len()))?;
+		Ok(resolved.map(|(value, namespace)| (value, namespace.into())))
+	}
+
+	fn item_royalty(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<(AccountId, Perbill)>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		api.item_royalty(at_hash, collection, item, ROYALTY_KEY.to_vec())
+			.map_err(|e| runtime_error(e, "Unable to query item royalty.").into())
+	}
+
+	fn item_attribute_query(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		query: Option<RpcNamespaceQuery>,
+		key: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<(Vec<u8>, RpcAttributeNamespace)>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let attribute = match query {
+			None => api.item_attribute_resolved(at_hash, collection, item, key),
+			Some(RpcNamespaceQuery::Exact(namespace)) => {
+				let namespace = decode_namespace::<AccountId>(namespace)?;
+				api.attribute(at_hash, collection, item, namespace.clone(), key)
+					.map(|value| value.map(|value| (value, namespace)))
+			},
+			Some(RpcNamespaceQuery::Any) => api.item_attribute_any(at_hash, collection, item, key),
+		}
+		.map_err(|e| runtime_error(e, "Unable to query item attribute."))?;
+		self.ensure_response_size(attribute.as_ref().map_or(0, |(value, _)| value.len()))?;
+		Ok(attribute.map(|(value, namespace)| (value, namespace.into())))
+	}
+
+	fn item_attribute_diff(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		from: Block::Hash,
+		to: Block::Hash,
+	) -> RpcResult<RpcAttributeDiff> {
+		let from_hash = self.block_hash(Some(from))?;
+		let to_hash = self.block_hash(Some(to))?;
+
+		let old_attributes = self.all_item_attributes(&collection, &item, &[], from_hash)?;
+		let mut new_attributes = self.all_item_attributes(&collection, &item, &[], to_hash)?;
+
+		let mut diff = RpcAttributeDiff::default();
+		for ((encoded_namespace, key), (namespace, old_value)) in old_attributes {
+			match new_attributes.remove(&(encoded_namespace, key.clone())) {
+				None => diff.removed.push(RpcAttributeChange { namespace, key, value: None }),
+				Some((_, value)) if value != old_value =>
+					diff.changed.push(RpcAttributeChange { namespace, key, value: Some(value) }),
+				Some(_) => (),
+			}
+		}
+		for ((_, key), (namespace, value)) in new_attributes {
+			diff.added.push(RpcAttributeChange { namespace, key, value: Some(value) });
+		}
+
+		self.ensure_response_size(
+			[&diff.added, &diff.changed, &diff.removed]
+				.into_iter()
+				.flatten()
+				.map(|change| change.key.len() + change.value.as_ref().map_or(0, Vec::len))
+				.sum(),
+		)?;
+		Ok(diff)
+	}
+
+	fn item_attributes_under_path(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		mut path: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(RpcAttributeNamespace, Vec<u8>)>> {
+		let at_hash = self.block_hash(at)?;
+
+		if !path.is_empty() {
+			path.push(well_known_keys::PATH_SEPARATOR);
+		}
+		let attributes = self.all_item_attributes(&collection, &item, &path, at_hash)?;
+		let keys: Vec<_> = attributes
+			.into_iter()
+			.map(|((_, key), (namespace, _))| (namespace, key))
+			.collect();
+		self.ensure_response_size(keys.iter().map(|(_, key)| key.len()).sum())?;
+		Ok(keys)
+	}
+
+	fn collection_attribute_with_deposit(
+		&self,
+		collection: CollectionId,
+		key: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<(Vec<u8>, Balance)>> {
+		let api = self.client.runtime_api();
+		let at_hash = self.block_hash(at)?;
+
+		let attribute = api
+			.collection_attribute_with_deposit(at_hash, collection, key)
+			.map_err(|e| runtime_error(e, "Unable to query collection attribute deposit."))?;
+		self.ensure_response_size(attribute.as_ref().map_or(0, |(value, _)| value.len()))?;
+		Ok(attribute)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn attribute_namespace_json_representation_should_work() {
+		let cases = [
+			(AttributeNamespace::Pallet, r#"{"pallet":null}"#),
+			(AttributeNamespace::CollectionOwner, r#"{"collectionOwner":null}"#),
+			(AttributeNamespace::ItemOwner, r#"{"itemOwner":null}"#),
+			(AttributeNamespace::Account(0x0102u16), r#"{"account":"0x0201"}"#),
+		];
+		for (namespace, json) in cases {
+			let rpc_namespace = RpcAttributeNamespace::from(namespace.clone());
+			assert_eq!(serde_json::to_string(&rpc_namespace).unwrap(), json);
+
+			let decoded: RpcAttributeNamespace = serde_json::from_str(json).unwrap();
+			assert_eq!(decoded.try_into_namespace::<u16>().unwrap(), namespace);
+		}
+
+		// the account is represented the same way it's SCALE-encoded on-chain
+		assert_eq!(
+			RpcAttributeNamespace::from(AttributeNamespace::Account(1u64)),
+			RpcAttributeNamespace::Account(1u64.encode().into())
+		);
+	}
+
+	#[test]
+	fn malformed_namespace_should_be_reported() {
+		match decode_namespace::<u64>(RpcAttributeNamespace::Account(vec![1, 2].into())) {
+			Err(CallError::Custom(error)) => {
+				assert_eq!(error.code(), i32::from(Error::InvalidNamespace));
+				assert_eq!(error.data().map(|data| data.get()), Some(r#""0x0102""#));
+			},
+			_ => panic!("the namespace must not be decodable"),
+		}
+		// the trailing bytes are not ignored
+		let mut encoded = 1u64.encode();
+		encoded.push(0);
+		assert!(decode_namespace::<u64>(RpcAttributeNamespace::Account(encoded.into())).is_err());
+
+		assert_eq!(
+			decode_namespace::<u64>(RpcAttributeNamespace::Account(1u64.encode().into())).ok(),
+			Some(AttributeNamespace::Account(1))
+		);
+	}
+
+	#[test]
+	fn namespace_query_json_representation_should_work() {
+		let cases = [
+			(RpcNamespaceQuery::Any, r#""any""#),
+			(
+				RpcNamespaceQuery::Exact(RpcAttributeNamespace::ItemOwner),
+				r#"{"exact":{"itemOwner":null}}"#,
+			),
+			(
+				RpcNamespaceQuery::Exact(RpcAttributeNamespace::Account(vec![1].into())),
+				r#"{"exact":{"account":"0x01"}}"#,
+			),
+		];
+		for (query, json) in cases {
+			assert_eq!(serde_json::to_string(&query).unwrap(), json);
+			assert_eq!(serde_json::from_str::<RpcNamespaceQuery>(json).unwrap(), query);
+		}
+		assert_eq!(serde_json::from_str::<Option<RpcNamespaceQuery>>("null").unwrap(), None);
+	}
+
+	#[test]
+	fn decoded_attribute_value_json_representation_should_work() {
+		let cases = [
+			(b"hello".to_vec(), AttributeValueType::Utf8, r#"{"type":"Utf8","value":"hello"}"#),
+			(42u128.encode(), AttributeValueType::U128, r#"{"type":"U128","value":"42"}"#),
+			(true.encode(), AttributeValueType::Bool, r#"{"type":"Bool","value":true}"#),
+			(vec![1, 2], AttributeValueType::Raw, r#"{"type":"Raw","value":"0x0102"}"#),
+			// malformed values fall back to the raw bytes
+			(vec![0xff], AttributeValueType::Utf8, r#"{"type":"Raw","value":"0xff"}"#),
+			(vec![1], AttributeValueType::U128, r#"{"type":"Raw","value":"0x01"}"#),
+		];
+		for (value, value_type, json) in cases {
+			let decoded = RpcDecodedAttributeValue::decode(value, value_type);
+			assert_eq!(serde_json::to_string(&decoded).unwrap(), json);
+		}
+	}
+
+	#[test]
+	fn attribute_cursor_json_representation_should_work() {
+		let cursor = AttributeCursor::from(vec![1, 2]);
+		assert_eq!(serde_json::to_string(&cursor).unwrap(), r#""0x0102""#);
+		let decoded: AttributeCursor = serde_json::from_str(r#""0x0102""#).unwrap();
+		assert_eq!(Vec::<u8>::from(decoded), vec![1, 2]);
+		assert!(serde_json::from_str::<AttributeCursor>(r#""0xzz""#).is_err());
+	}
+
+	#[test]
+	fn api_version_json_representation_should_work() {
+		assert_eq!(serde_json::to_string(&RpcApiVersion::new(1)).unwrap(), r#"{"version":1}"#);
+	}
+
+	#[test]
+	fn item_info_json_representation_should_work() {
+		let info = RpcItemInfo::from(ItemInfo {
+			owner: 1u64,
+			metadata: Some(vec![1, 2]),
+			settings: 0,
+			attribute_count: 3,
+		});
+		assert_eq!(
+			serde_json::to_string(&info).unwrap(),
+			r#"{"owner":1,"metadata":[1,2],"settings":0,"attributeCount":3}"#
+		);
+	}
+
+	#[test]
+	fn attribute_changes_json_representation_should_work() {
+		let changes = RpcItemAttributeChanges {
+			block: 1u64,
+			changes: vec![
+				AttributeChange {
+					namespace: AttributeNamespace::<u64>::ItemOwner,
+					key: vec![1],
+					value: Some(vec![2]),
+				}
+				.into(),
+				AttributeChange {
+					namespace: AttributeNamespace::Account(0x0102u16),
+					key: vec![1],
+					value: None,
+				}
+				.into(),
+			],
+		};
+		assert_eq!(
+			serde_json::to_string(&changes).unwrap(),
+			r#"{"block":1,"changes":[{"namespace":{"itemOwner":null},"key":[1],"value":[2]},{"namespace":{"account":"0x0201"},"key":[1],"value":null}]}"#
+		);
+	}
+
+	#[test]
+	fn attribute_diff_json_representation_should_work() {
+		let diff = RpcAttributeDiff {
+			added: vec![RpcAttributeChange {
+				namespace: RpcAttributeNamespace::ItemOwner,
+				key: vec![1],
+				value: Some(vec![2]),
+			}],
+			changed: vec![],
+			removed: vec![RpcAttributeChange {
+				namespace: RpcAttributeNamespace::Pallet,
+				key: vec![3],
+				value: None,
+			}],
+		};
+		assert_eq!(
+			serde_json::to_string(&diff).unwrap(),
+			r#"{"added":[{"namespace":{"itemOwner":null},"key":[1],"value":[2]}],"changed":[],"removed":[{"namespace":{"pallet":null},"key":[3],"value":null}]}"#
+		);
+	}
+}
@@ -382,6 +382,38 @@ benchmarks_instance_pallet! {
 				namespace: AttributeNamespace::CollectionOwner,
 				key,
 				value,
+				old_value: None,
+			}
+			.into(),
+		);
+	}
+
+	set_attributes {
+		let n in 1 .. T::MaxAttributesPerCall::get() as u32;
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+
+		// every key is new, so the deposit is reserved for each of them
+		let value: BoundedVec<_, _> = vec![0u8; T::ValueLimit::get() as usize].try_into().unwrap();
+		let attributes: BoundedVec<_, _> = (0..n)
+			.map(|i| {
+				let key = make_filled_vec(i as u16, T::KeyLimit::get() as usize);
+				(key.try_into().unwrap(), value.clone())
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let (last_key, _) = attributes.last().cloned().unwrap();
+	}: _(SystemOrigin::Signed(caller), collection, Some(item), AttributeNamespace::CollectionOwner, attributes)
+	verify {
+		assert_last_event::<T, I>(
+			Event::AttributeSet {
+				collection,
+				maybe_item: Some(item),
+				namespace: AttributeNamespace::CollectionOwner,
+				key: last_key,
+				value,
+				old_value: None,
 			}
 			.into(),
 		);
@@ -393,7 +425,7 @@ benchmarks_instance_pallet! {
 
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
-	}: _(SystemOrigin::Root, Some(caller), collection, Some(item), AttributeNamespace::CollectionOwner, key.clone(), value.clone())
+	}: _(SystemOrigin::Root, Some(caller), collection, Some(item), AttributeNamespace::CollectionOwner, key.clone(), value.clone(), false)
 	verify {
 		assert_last_event::<T, I>(
 			Event::AttributeSet {
@@ -402,6 +434,7 @@ benchmarks_instance_pallet! {
 				namespace: AttributeNamespace::CollectionOwner,
 				key,
 				value,
+				old_value: None,
 			}
 			.into(),
 		);
@@ -412,6 +445,9 @@ benchmarks_instance_pallet! {
 		let (item, ..) = mint_item::<T, I>(0);
 		add_item_metadata::<T, I>(item);
 		let (key, ..) = add_item_attribute::<T, I>(item);
+		let (value, deposit) = Attribute::<T, I>::get(
+			(collection, Some(item), AttributeNamespace::CollectionOwner, &key)
+		).unwrap();
 	}: _(SystemOrigin::Signed(caller), collection, Some(item), AttributeNamespace::CollectionOwner, key.clone())
 	verify {
 		assert_last_event::<T, I>(
@@ -420,6 +456,8 @@ benchmarks_instance_pallet! {
 				maybe_item: Some(item),
 				namespace: AttributeNamespace::CollectionOwner,
 				key,
+				value,
+				deposit: deposit.amount,
 			}.into(),
 		);
 	}
@@ -442,7 +480,7 @@ benchmarks_instance_pallet! {
 	}
 
 	cancel_item_attributes_approval {
-		let n in 0 .. 1_000;
+		let n in 0 .. T::MaxAttributesPerItem::get();
 
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
@@ -468,6 +506,7 @@ benchmarks_instance_pallet! {
 			)?;
 		}
 		let witness = CancelAttributesApprovalWitness { account_attributes: n };
+		let deposit = T::Currency::reserved_balance(&target);
 	}: _(SystemOrigin::Signed(caller), collection, item, target_lookup, witness)
 	verify {
 		assert_last_event::<T, I>(
@@ -475,6 +514,8 @@ benchmarks_instance_pallet! {
 				collection,
 				item,
 				delegate: target,
+				attributes_removed: n,
+				deposit_refunded: deposit,
 			}
 			.into(),
 		);
@@ -823,5 +864,243 @@ benchmarks_instance_pallet! {
 		);
 	}
 
+	recalculate_attribute_deposit {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let (key, ..) = add_item_attribute::<T, I>(item);
+
+		// the deposit was reserved under the higher deposit parameters, so a part gets refunded
+		let extra = DepositBalanceOf::<T, I>::from(1u32);
+		T::Currency::reserve(&caller, extra)?;
+		Collection::<T, I>::mutate(collection, |maybe_details| {
+			maybe_details.as_mut().unwrap().owner_deposit.saturating_accrue(extra)
+		});
+		Attribute::<T, I>::mutate(
+			(collection, Some(item), AttributeNamespace::CollectionOwner, &key),
+			|maybe_attribute| maybe_attribute.as_mut().unwrap().1.amount.saturating_accrue(extra),
+		);
+		let (_, deposit) = Attribute::<T, I>::get(
+			(collection, Some(item), AttributeNamespace::CollectionOwner, &key)
+		).unwrap();
+	}: _(SystemOrigin::Signed(caller), collection, Some(item), AttributeNamespace::CollectionOwner, key.clone())
+	verify {
+		assert_last_event::<T, I>(
+			Event::AttributeDepositUpdated {
+				collection,
+				maybe_item: Some(item),
+				namespace: AttributeNamespace::CollectionOwner,
+				key,
+				old_deposit: deposit.amount,
+				new_deposit: deposit.amount - extra,
+			}
+			.into(),
+		);
+	}
+
+	clear_item_namespace {
+		let n in 0 .. T::MaxAttributesPerItem::get();
+
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let value: BoundedVec<_, _> = vec![0u8; T::ValueLimit::get() as usize].try_into().unwrap();
+		for i in 0..n {
+			let key = make_filled_vec(i as u16, T::KeyLimit::get() as usize);
+			Nfts::<T, I>::set_attribute(
+				SystemOrigin::Signed(caller.clone()).into(),
+				collection,
+				Some(item),
+				AttributeNamespace::CollectionOwner,
+				key.try_into().unwrap(),
+				value.clone(),
+			)?;
+		}
+		let witness = ClearItemNamespaceWitness { attributes: n };
+	}: _(SystemOrigin::Signed(caller), collection, item, AttributeNamespace::CollectionOwner, witness)
+	verify {
+		assert_last_event::<T, I>(
+			Event::ItemNamespaceCleared {
+				collection,
+				item,
+				namespace: AttributeNamespace::CollectionOwner,
+				attributes: n,
+			}
+			.into(),
+		);
+	}
+
+	transfer_item_attributes {
+		let n in 0 .. T::MaxAttributesPerItem::get();
+
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let value: BoundedVec<_, _> = vec![0u8; T::ValueLimit::get() as usize].try_into().unwrap();
+		for i in 0..n {
+			let key = make_filled_vec(i as u16, T::KeyLimit::get() as usize);
+			Nfts::<T, I>::set_attribute(
+				SystemOrigin::Signed(caller.clone()).into(),
+				collection,
+				Some(item),
+				AttributeNamespace::ItemOwner,
+				key.try_into().unwrap(),
+				value.clone(),
+			)?;
+		}
+		let target: T::AccountId = account("target", 0, SEED);
+		T::Currency::make_free_balance_be(&target, DepositBalanceOf::<T, I>::max_value());
+		// the attributes stay with the previous owner regardless of `T::AutoMoveItemAttributes`
+		Item::<T, I>::mutate(collection, item, |maybe_details| {
+			maybe_details.as_mut().unwrap().owner = target.clone()
+		});
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let witness = TransferItemAttributesWitness { attributes: n };
+	}: _(SystemOrigin::Signed(target.clone()), collection, item, caller_lookup, witness)
+	verify {
+		assert_last_event::<T, I>(
+			Event::ItemAttributesTransferred {
+				collection,
+				item,
+				from: caller,
+				to: target,
+				attributes: n,
+			}
+			.into(),
+		);
+	}
+
+	clear_orphaned_attributes {
+		let n in 0 .. T::MaxAttributesPerItem::get();
+
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let value: BoundedVec<_, _> = vec![0u8; T::ValueLimit::get() as usize].try_into().unwrap();
+		for i in 0..n {
+			let key = make_filled_vec(i as u16, T::KeyLimit::get() as usize);
+			Nfts::<T, I>::set_attribute(
+				SystemOrigin::Signed(caller.clone()).into(),
+				collection,
+				Some(item),
+				AttributeNamespace::CollectionOwner,
+				key.try_into().unwrap(),
+				value.clone(),
+			)?;
+		}
+		Nfts::<T, I>::burn(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			item,
+			Some(caller_lookup),
+		)?;
+		let witness = ClearOrphanedAttributesWitness { attributes: n };
+	}: _(SystemOrigin::Signed(caller), collection, item, witness)
+	verify {
+		assert_last_event::<T, I>(
+			Event::OrphanedAttributesCleared { collection, item, attributes: n }.into(),
+		);
+	}
+
+	set_delegate_attributes_sponsorship {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+	}: _(SystemOrigin::Signed(caller), collection, item, true)
+	verify {
+		assert_last_event::<T, I>(
+			Event::DelegateAttributesSponsorshipSet { collection, item, sponsor: true }.into(),
+		);
+	}
+
+	lock_attribute {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let (key, ..) = add_item_attribute::<T, I>(item);
+	}: _(SystemOrigin::Signed(caller), collection, Some(item), key.clone())
+	verify {
+		assert_last_event::<T, I>(
+			Event::AttributeKeyLocked { collection, maybe_item: Some(item), key }.into(),
+		);
+	}
+
+	set_attribute_schema {
+		let n in 0 .. T::MaxAttributeValueOptions::get();
+
+		let (collection, caller, _) = create_collection::<T, I>();
+		let key: BoundedVec<_, _> = vec![0u8; T::KeyLimit::get() as usize].try_into().unwrap();
+		let options: BoundedVec<_, _> = (0..n)
+			.map(|i| make_filled_vec(i as u16, T::ValueLimit::get() as usize).try_into().unwrap())
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let constraint = Some(ValueConstraint::OneOf(options));
+	}: _(SystemOrigin::Signed(caller), collection, key.clone(), constraint.clone())
+	verify {
+		assert_last_event::<T, I>(Event::AttributeSchemaSet { collection, key, constraint }.into());
+	}
+
+	clear_collection_attributes {
+		let n in 0 .. T::MaxAttributesPerItem::get();
+
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let value: BoundedVec<_, _> = vec![0u8; T::ValueLimit::get() as usize].try_into().unwrap();
+		for i in 0..n {
+			let key = make_filled_vec(i as u16, T::KeyLimit::get() as usize);
+			Nfts::<T, I>::set_attribute(
+				SystemOrigin::Signed(caller.clone()).into(),
+				collection,
+				Some(item),
+				AttributeNamespace::CollectionOwner,
+				key.try_into().unwrap(),
+				value.clone(),
+			)?;
+		}
+		Nfts::<T, I>::burn(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			item,
+			Some(caller_lookup),
+		)?;
+		let witness = AttributeCleanupWitness { attributes: n };
+	}: _(SystemOrigin::Signed(caller), collection, witness)
+	verify {
+		assert_last_event::<T, I>(
+			Event::CollectionAttributesCleared { collection, attributes: n, remaining: 0 }.into(),
+		);
+	}
+
+	add_collection_attribute_editor {
+		let (collection, caller, _) = create_collection::<T, I>();
+		for i in 1..T::CollectionAttributeEditorsLimit::get() {
+			let editor: T::AccountId = account("editor", i, SEED);
+			Nfts::<T, I>::add_collection_attribute_editor(
+				SystemOrigin::Signed(caller.clone()).into(),
+				collection,
+				T::Lookup::unlookup(editor),
+			)?;
+		}
+		let editor: T::AccountId = account("editor", 0, SEED);
+		let editor_lookup = T::Lookup::unlookup(editor.clone());
+	}: _(SystemOrigin::Signed(caller), collection, editor_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionAttributeEditorAdded { collection, editor }.into());
+	}
+
+	remove_collection_attribute_editor {
+		let (collection, caller, _) = create_collection::<T, I>();
+		for i in 0..T::CollectionAttributeEditorsLimit::get() {
+			let editor: T::AccountId = account("editor", i, SEED);
+			Nfts::<T, I>::add_collection_attribute_editor(
+				SystemOrigin::Signed(caller.clone()).into(),
+				collection,
+				T::Lookup::unlookup(editor),
+			)?;
+		}
+		let editor: T::AccountId = account("editor", 0, SEED);
+		let editor_lookup = T::Lookup::unlookup(editor.clone());
+	}: _(SystemOrigin::Signed(caller), collection, editor_lookup)
+	verify {
+		assert_last_event::<T, I>(
+			Event::CollectionAttributeEditorRemoved { collection, editor }.into(),
+		);
+	}
+
 	impl_benchmark_test_suite!(Nfts, crate::mock::new_test_ext(), crate::mock::Test);
 }
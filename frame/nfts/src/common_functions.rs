@@ -17,7 +17,10 @@
 
 //! Various pieces of common functionality.
 
-use crate::*;
+use crate::{well_known_keys::WellKnownKey, *};
+use codec::DecodeAll;
+use frame_support::{ensure, traits::tokens::nonfungibles_v2::Inspect, BoundedSlice, BoundedVec};
+use sp_runtime::{DispatchError, Perbill, Permill};
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Get the owner of the item, if the item exists.
@@ -30,6 +33,792 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Collection::<T, I>::get(collection).map(|i| i.owner)
 	}
 
+	/// Get the metadata of the item, if it was set.
+	pub fn item_metadata(collection: T::CollectionId, item: T::ItemId) -> Option<Vec<u8>> {
+		ItemMetadataOf::<T, I>::get(collection, item).map(|m| m.data.into())
+	}
+
+	/// Get the metadata of the collection, if it was set.
+	pub fn collection_metadata(collection: T::CollectionId) -> Option<Vec<u8>> {
+		CollectionMetadataOf::<T, I>::get(collection).map(|m| m.data.into())
+	}
+
+	/// Get the bitflags of the item's disabled settings, if the item exists.
+	///
+	/// NOTE: the config of a burned item might be preserved, so we check the item exists first.
+	pub fn item_settings(collection: T::CollectionId, item: T::ItemId) -> Option<u64> {
+		if !Item::<T, I>::contains_key(&collection, &item) {
+			return None
+		}
+		Self::get_item_config(&collection, &item)
+			.ok()
+			.map(|c| c.settings.get_disabled().bits())
+	}
+
+	/// Check whether the `CollectionOwner` namespace attributes of the item are locked.
+	///
+	/// NOTE: when the item's config is missing (e.g. the item was burned), the collection's lock
+	/// state is reported instead.
+	pub fn item_attributes_locked(collection: T::CollectionId, item: T::ItemId) -> bool {
+		match ItemConfigOf::<T, I>::get(&collection, &item) {
+			Some(config) => config.has_disabled_setting(ItemSetting::UnlockedAttributes),
+			None => CollectionConfigOf::<T, I>::get(&collection).map_or(false, |config| {
+				config.has_disabled_setting(CollectionSetting::UnlockedAttributes)
+			}),
+		}
+	}
+
+	/// Get the item's owner, metadata, settings and the number of attributes at once, if the item
+	/// exists.
+	pub fn item_info(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> Option<ItemInfo<T::AccountId>> {
+		let owner = Self::owner(collection, item)?;
+		let settings =
+			Self::get_item_config(&collection, &item).ok()?.settings.get_disabled().bits();
+		Some(ItemInfo {
+			owner,
+			metadata: Self::item_metadata(collection, item),
+			settings,
+			attribute_count: ItemAttributeCountOf::<T, I>::get(collection, item),
+		})
+	}
+
+	/// Get the changes of the item's attributes made within the current block, as reported by the
+	/// `AttributeSet` and `AttributeCleared` events.
+	///
+	/// NOTE: the events are read from the storage, so this is only meant to be called off-chain.
+	pub fn item_attribute_changes(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> Vec<AttributeChange<T::AccountId>>
+	where
+		<T as SystemConfig>::RuntimeEvent: TryInto<Event<T, I>>,
+	{
+		frame_system::Pallet::<T>::read_events_no_consensus()
+			.filter_map(|record| record.event.try_into().ok())
+			.filter_map(|event| {
+				let (c, maybe_item, namespace, key, value) = match event {
+					Event::AttributeSet {
+						collection, maybe_item, key, value, namespace, ..
+					} => (collection, maybe_item, namespace, key, Some(value.into())),
+					Event::AttributeCleared { collection, maybe_item, key, namespace, .. } =>
+						(collection, maybe_item, namespace, key, None),
+					_ => return None,
+				};
+				(c == collection && maybe_item == Some(item)).then(|| AttributeChange {
+					namespace,
+					key: key.into(),
+					value,
+				})
+			})
+			.collect()
+	}
+
+	/// Get the number of attributes stored within the collection, if the collection exists.
+	pub fn collection_attributes_count(collection: T::CollectionId) -> Option<u32> {
+		Collection::<T, I>::get(collection).map(|c| c.attributes)
+	}
+
+	/// Get the number of attributes stored within the collection in the namespaces of the `kind`,
+	/// if the collection exists.
+	pub fn namespace_attribute_count(
+		collection: T::CollectionId,
+		kind: NamespaceDiscriminant,
+	) -> Option<u32> {
+		if !Collection::<T, I>::contains_key(collection) {
+			return None
+		}
+		Some(CollectionNamespaceAttributeCountOf::<T, I>::get(collection, kind))
+	}
+
+	/// Get the total deposit reserved from the collection's owner, if the collection exists.
+	///
+	/// NOTE: apart from the collection deposit itself, this includes the deposits for the
+	/// collection's and items' metadata and the `CollectionOwner` namespace attributes paid by
+	/// the owner.
+	pub fn collection_owner_deposit(collection: T::CollectionId) -> Option<DepositBalanceOf<T, I>> {
+		Collection::<T, I>::get(collection).map(|c| c.owner_deposit)
+	}
+
+	/// Get the value of the item's attribute along with the account that paid the deposit for it
+	/// and the deposit amount. The expired attribute is skipped and the compressed value is
+	/// decompressed.
+	///
+	/// NOTE: `None` as a depositor of the `CollectionOwner` namespace attribute means the deposit
+	/// was paid by the collection's owner.
+	pub fn attribute_with_deposit(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: &[u8],
+	) -> Option<(Vec<u8>, Option<T::AccountId>, DepositBalanceOf<T, I>)> {
+		let key = BoundedSlice::<_, _>::try_from(key).ok()?;
+		let (value, deposit) = Attribute::<T, I>::get((collection, Some(item), namespace, key))?;
+		let value =
+			Self::read_attribute_value(collection, Some(*item), namespace, key, value.into())?;
+		Some((value, deposit.account, deposit.amount))
+	}
+
+	/// Get the value of the collection's attribute along with the part of the collection owner's
+	/// deposit it accounts for. The expired attribute is skipped and the compressed value is
+	/// decompressed.
+	///
+	/// NOTE: that's the attribute's deposit if it was paid by the collection's owner, and zero if
+	/// it was paid by another account, e.g. the one it was force-set on behalf of.
+	pub fn collection_attribute_with_deposit(
+		collection: &T::CollectionId,
+		key: &[u8],
+	) -> Option<(Vec<u8>, DepositBalanceOf<T, I>)> {
+		let key = BoundedSlice::<_, _>::try_from(key).ok()?;
+		let namespace = AttributeNamespace::CollectionOwner;
+		let (value, deposit) =
+			Attribute::<T, I>::get((collection, Option::<T::ItemId>::None, &namespace, key))?;
+		let value = Self::read_attribute_value(collection, None, &namespace, key, value.into())?;
+		let owner_deposit = match deposit.account {
+			None => deposit.amount,
+			Some(_) => Zero::zero(),
+		};
+		Some((value, owner_deposit))
+	}
+
+	/// Get the value of the item's attribute within the `namespace`, telling a missing collection
+	/// or item apart from an absent attribute.
+	///
+	/// When `key` is empty, the item's metadata is returned.
+	pub fn attribute_checked(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: Vec<u8>,
+		namespace: AttributeNamespace<T::AccountId>,
+	) -> Result<Option<Vec<u8>>, Error<T, I>> {
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		ensure!(Item::<T, I>::contains_key(&collection, &item), Error::<T, I>::UnknownItem);
+		Ok(<Self as Inspect<T::AccountId>>::attribute(&collection, &item, &namespace, &key))
+	}
+
+	/// Get the final storage key of the item's attribute within the `namespace`, so its value
+	/// can be proven with a storage proof.
+	///
+	/// Returns `None` if the `key` exceeds the `T::KeyLimit`.
+	pub fn item_attribute_storage_key(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: Vec<u8>,
+		namespace: AttributeNamespace<T::AccountId>,
+	) -> Option<Vec<u8>> {
+		let key = BoundedSlice::<_, T::KeyLimit>::try_from(&key[..]).ok()?;
+		Some(Attribute::<T, I>::hashed_key_for((collection, Some(item), namespace, key)))
+	}
+
+	/// Get the value of the `key` attribute within the `namespace` for each of the `items`, in the
+	/// same order as the `items` were provided. The compressed values are decompressed.
+	pub fn items_attribute(
+		collection: T::CollectionId,
+		items: Vec<T::ItemId>,
+		key: Vec<u8>,
+		namespace: AttributeNamespace<T::AccountId>,
+	) -> Vec<Option<Vec<u8>>> {
+		let Ok(key) = BoundedVec::<u8, T::KeyLimit>::try_from(key) else {
+			return items.iter().map(|_| None).collect()
+		};
+		items
+			.into_iter()
+			.map(|item| {
+				let (value, _) =
+					Attribute::<T, I>::get((&collection, Some(item), &namespace, &key))?;
+				Self::read_attribute_value(
+					&collection,
+					Some(item),
+					&namespace,
+					key.as_bounded_slice(),
+					value.into(),
+				)
+			})
+			.collect()
+	}
+
+	/// Get the value of the item's attribute along with the type of that value, unless the
+	/// attribute has expired. The compressed value is decompressed.
+	pub fn typed_attribute(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: Vec<u8>,
+	) -> Option<(Vec<u8>, AttributeValueType)> {
+		let key = BoundedVec::<u8, T::KeyLimit>::try_from(key).ok()?;
+		let (value, _) = Attribute::<T, I>::get((&collection, Some(item), &namespace, &key))?;
+		let value = Self::read_attribute_value(
+			&collection,
+			Some(item),
+			&namespace,
+			key.as_bounded_slice(),
+			value.into(),
+		)?;
+		let value_type =
+			AttributeValueTypeOf::<T, I>::get((&collection, Some(item), &namespace, &key))
+				.unwrap_or_default();
+		Some((value, value_type))
+	}
+
+	/// Get the value of the item's attribute set with `set_large_attribute`, reassembled from its
+	/// chunks.
+	pub fn large_attribute(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: Vec<u8>,
+	) -> Option<Vec<u8>> {
+		let key = BoundedVec::<u8, T::KeyLimit>::try_from(key).ok()?;
+		let chunks_count = Self::attribute_chunks_count(&collection, Some(item), &namespace, &key)?;
+
+		let mut value = Vec::new();
+		for index in 0..chunks_count {
+			let chunk_key = Self::attribute_chunk_key(&key, index).ok()?;
+			let (chunk, _) =
+				Attribute::<T, I>::get((&collection, Some(item), &namespace, &chunk_key))?;
+			value.extend(chunk);
+		}
+		Some(value)
+	}
+
+	/// Check whether the item's attribute exists within the `namespace` and hasn't expired
+	/// without reading its value.
+	pub fn has_attribute(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: Vec<u8>,
+		namespace: AttributeNamespace<T::AccountId>,
+	) -> bool {
+		BoundedVec::<u8, T::KeyLimit>::try_from(key).map_or(false, |key| {
+			Attribute::<T, I>::contains_key((&collection, Some(item), &namespace, &key)) &&
+				!Self::is_attribute_expired(&collection, Some(item), &namespace, &key)
+		})
+	}
+
+	/// Get the block at which the item's attribute within the `namespace` was last set.
+	///
+	/// Attributes that haven't been rewritten since the tracking was introduced are reported as
+	/// set at the block zero.
+	pub fn attribute_last_set(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: Vec<u8>,
+		namespace: AttributeNamespace<T::AccountId>,
+	) -> Option<<T as SystemConfig>::BlockNumber> {
+		let key = BoundedVec::<u8, T::KeyLimit>::try_from(key).ok()?;
+		let storage_key = (&collection, Some(item), &namespace, &key);
+		Attribute::<T, I>::contains_key(storage_key)
+			.then(|| AttributeLastSetOf::<T, I>::get(storage_key))
+	}
+
+	/// Check whether the item holds any attribute within any namespace.
+	///
+	/// Only the first attribute's key is read, so the check is cheap regardless of the number
+	/// of the item's attributes.
+	pub fn item_has_any_attribute(collection: T::CollectionId, item: T::ItemId) -> bool {
+		Attribute::<T, I>::iter_key_prefix((&collection, Some(item))).next().is_some()
+	}
+
+	/// Get the sum of the deposits of the item's attributes within all the namespaces.
+	///
+	/// That's the amount refunded once all the item's attributes are cleared, no matter who paid
+	/// the deposits.
+	///
+	/// NOTE: iterates over all the item's attributes, so the cost is `O(A)` where `A` is the
+	/// number of them.
+	pub fn item_total_attribute_deposit(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DepositBalanceOf<T, I> {
+		Attribute::<T, I>::iter_prefix_values((&collection, Some(item)))
+			.fold(Zero::zero(), |total, (_, deposit)| total.saturating_add(deposit.amount))
+	}
+
+	/// Get the namespace the `key` attribute of the item is resolved from according to the
+	/// `T::NamespacePrecedence`, if the attribute exists.
+	pub fn resolve_attribute_namespace(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: Vec<u8>,
+	) -> Option<AttributeNamespace<T::AccountId>> {
+		T::NamespacePrecedence::namespace_precedence_opt(&collection, &item, &key)
+	}
+
+	/// Get the value of the item's `key` attribute resolved according to the
+	/// `T::NamespacePrecedence`, falling back to the item's metadata when there is no such
+	/// attribute.
+	pub fn item_metadata_uri(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: Vec<u8>,
+	) -> Option<Vec<u8>> {
+		Self::resolved_attribute(&collection, &item, &key)
+			.or_else(|| Self::item_metadata(collection, item))
+	}
+
+	/// Get the value of the item's well-known attribute resolved according to the
+	/// `T::NamespacePrecedence`.
+	pub fn well_known_attribute(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: WellKnownKey,
+	) -> Option<Vec<u8>> {
+		Self::resolved_attribute(&collection, &item, key.as_bytes())
+	}
+
+	/// Get the royalty terms stored within the item's `key` attribute resolved according to the
+	/// `T::NamespacePrecedence`.
+	///
+	/// The value is expected to be the SCALE-encoded `(AccountId, Permill)` of the beneficiary and
+	/// the royalty. Returns `None` if there is no such attribute or its value can't be decoded.
+	pub fn item_royalty(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: Vec<u8>,
+	) -> Option<(T::AccountId, Perbill)> {
+		let value = Self::resolved_attribute(&collection, &item, &key)?;
+		let (beneficiary, royalty) = <(T::AccountId, Permill)>::decode_all(&mut &value[..]).ok()?;
+		Some((beneficiary, Perbill::from_parts(royalty.deconstruct().saturating_mul(1_000))))
+	}
+
+	/// Get the value of the item's `key` attribute resolved according to the
+	/// `T::NamespacePrecedence` along with the namespace it was resolved from, unless the
+	/// attribute has expired. The compressed value is decompressed.
+	pub fn item_attribute_resolved(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: Vec<u8>,
+	) -> Option<(Vec<u8>, AttributeNamespace<T::AccountId>)> {
+		let (namespace, value) =
+			T::NamespacePrecedence::resolve_attribute(&collection, &item, &key)?;
+		let bounded_key = BoundedSlice::<_, T::KeyLimit>::try_from(&key[..]).ok()?;
+		let value = Self::read_attribute_value(
+			&collection,
+			Some(item),
+			&namespace,
+			bounded_key,
+			value.into(),
+		)?;
+		Some((value, namespace))
+	}
+
+	/// Get the value of the item's `key` attribute within any namespace along with the namespace
+	/// it was found in.
+	///
+	/// The namespaces are consulted in the `T::NamespacePrecedence` order first, followed by the
+	/// namespaces of the accounts that have set any of the item's attributes. The expired
+	/// attributes are skipped and the compressed value is decompressed.
+	pub fn item_attribute_any(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: Vec<u8>,
+	) -> Option<(Vec<u8>, AttributeNamespace<T::AccountId>)> {
+		Self::item_attribute_resolved(collection, item, key.clone()).or_else(|| {
+			let bounded_key = BoundedSlice::<_, T::KeyLimit>::try_from(&key[..]).ok()?;
+			Self::item_attribute_accounts(collection, item)
+				.into_iter()
+				.map(AttributeNamespace::Account)
+				.find_map(|namespace| {
+					let (value, _) =
+						Attribute::<T, I>::get((collection, Some(item), &namespace, bounded_key))?;
+					let value = Self::read_attribute_value(
+						&collection,
+						Some(item),
+						&namespace,
+						bounded_key,
+						value.into(),
+					)?;
+					Some((value, namespace))
+				})
+		})
+	}
+
+	/// Get the value of the item's `key` attribute resolved according to the
+	/// `T::NamespacePrecedence`, unless the attribute has expired.
+	fn resolved_attribute(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		key: &[u8],
+	) -> Option<Vec<u8>> {
+		Self::item_attribute_resolved(*collection, *item, key.to_vec()).map(|(value, _)| value)
+	}
+
+	/// Get the namespaces consulted when the item's `key` attribute is resolved, in the order
+	/// they are consulted according to the `T::NamespacePrecedence`.
+	pub fn namespace_precedence_order(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: Vec<u8>,
+	) -> Vec<AttributeNamespace<T::AccountId>> {
+		T::NamespacePrecedence::namespace_precedence_order(&collection, &item, &key)
+	}
+
+	/// Get every namespace the item's `key` attribute is present in, along with the value stored
+	/// within that namespace.
+	///
+	/// The `CollectionOwner`, `ItemOwner` and `Pallet` namespaces are probed first, followed by
+	/// the namespaces of the accounts that have set any of the item's attributes, see
+	/// `item_attribute_accounts()`. The expired attributes are skipped and the compressed values
+	/// are decompressed.
+	pub fn attribute_namespaces(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: Vec<u8>,
+	) -> Vec<(AttributeNamespace<T::AccountId>, Vec<u8>)> {
+		let Ok(key) = BoundedVec::<u8, T::KeyLimit>::try_from(key) else { return Vec::new() };
+		let accounts = Self::item_attribute_accounts(collection, item);
+		[
+			AttributeNamespace::CollectionOwner,
+			AttributeNamespace::ItemOwner,
+			AttributeNamespace::Pallet,
+		]
+		.into_iter()
+		.chain(accounts.into_iter().map(AttributeNamespace::Account))
+		.filter_map(|namespace| {
+			let (value, _) = Attribute::<T, I>::get((&collection, Some(item), &namespace, &key))?;
+			let value = Self::read_attribute_value(
+				&collection,
+				Some(item),
+				&namespace,
+				key.as_bounded_slice(),
+				value.into(),
+			)?;
+			Some((namespace, value))
+		})
+		.collect()
+	}
+
+	/// Get the accounts holding any of the item's attributes within their own namespaces.
+	///
+	/// Includes both the accounts approved to set the item's attributes and the collection's
+	/// attribute editors, as well as the former ones whose attributes haven't been cleared.
+	pub fn item_attribute_accounts(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> Vec<T::AccountId> {
+		let mut accounts: Vec<_> = Attribute::<T, I>::iter_key_prefix((&collection, Some(item)))
+			.filter_map(|(namespace, _)| match namespace {
+				AttributeNamespace::Account(account) => Some(account),
+				_ => None,
+			})
+			.collect();
+		// the attributes of the same namespace are iterated one after another
+		accounts.dedup();
+		accounts
+	}
+
+	/// Get the number of attributes set by the `delegate` within its namespace of an item.
+	pub fn item_attributes_delegate_count(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		delegate: T::AccountId,
+	) -> u32 {
+		ItemAttributesDelegateCount::<T, I>::get((&collection, &item, &delegate))
+			.unwrap_or_else(|| Self::count_delegate_attributes(&collection, item, &delegate))
+	}
+
+	/// Get the number of accounts approved to set the item's attributes.
+	pub fn item_attribute_approvals_count(collection: T::CollectionId, item: T::ItemId) -> u32 {
+		ItemAttributesApprovalsOf::<T, I>::decode_len(&collection, &item).unwrap_or_default() as u32
+	}
+
+	/// Check whether the `account` is approved to set the item's attributes.
+	pub fn is_item_attribute_delegate(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		account: T::AccountId,
+	) -> bool {
+		ItemAttributesApprovalsOf::<T, I>::get(&collection, &item).contains(&account)
+	}
+
+	/// Calculate the deposit required to store an attribute with the key and the value of the
+	/// provided lengths within the `namespace`.
+	///
+	/// NOTE: attributes within the `CollectionOwner` namespace don't require any deposit unless
+	/// the collection has the `DepositRequired` setting enabled.
+	pub fn compute_attribute_deposit(
+		collection: T::CollectionId,
+		namespace: AttributeNamespace<T::AccountId>,
+		key_len: u32,
+		value_len: u32,
+	) -> Option<DepositBalanceOf<T, I>> {
+		let collection_config = Self::get_collection_config(&collection).ok()?;
+		if namespace == AttributeNamespace::CollectionOwner &&
+			!collection_config.is_setting_enabled(CollectionSetting::DepositRequired)
+		{
+			return Some(Zero::zero())
+		}
+		Some(
+			T::DepositPerByte::get()
+				.saturating_mul(key_len.saturating_add(value_len).into())
+				.saturating_add(T::AttributeDepositBase::get()),
+		)
+	}
+
+	/// Get the accounts approved to set the item's attributes.
+	pub fn item_attribute_approvals(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> Vec<T::AccountId> {
+		ItemAttributesApprovalsOf::<T, I>::get(&collection, &item).into_iter().collect()
+	}
+
+	/// Get the accounts approved to set the item's attributes along with the number of attributes
+	/// each of them has set.
+	pub fn item_attribute_delegates(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> Vec<(T::AccountId, u32)> {
+		ItemAttributesApprovalsOf::<T, I>::get(&collection, &item)
+			.into_iter()
+			.map(|delegate| {
+				let attributes =
+					Self::item_attributes_delegate_count(collection, item, delegate.clone());
+				(delegate, attributes)
+			})
+			.collect()
+	}
+
+	/// Get up to `limit` attributes of the item within the `namespace`.
+	///
+	/// When `start_key` is provided, the attributes are returned starting right after that key.
+	/// Along with the key-value pairs, the key to continue the iteration from is returned in case
+	/// there are more attributes left. The expired attributes are skipped and the compressed
+	/// values are decompressed.
+	pub fn item_attributes(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		namespace: AttributeNamespace<T::AccountId>,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+	) -> (Vec<(Vec<u8>, Vec<u8>)>, Option<Vec<u8>>) {
+		Self::item_attributes_filtered(collection, item, namespace, Vec::new(), start_key, limit)
+	}
+
+	/// Get a lazy iterator over the attributes of the collection or of its item within the
+	/// `namespace`, as key-value pairs.
+	///
+	/// Meant for the other pallets and the off-chain workers. Every step of the iteration reads
+	/// one storage entry, so when used on-chain, the caller has to bound the iteration (the
+	/// item's attributes are bounded by the `T::MaxAttributesPerItem`) and account for the
+	/// weight of the reads. The attributes are not iterated in the order of their keys. The
+	/// expired attributes are skipped and the compressed values are decompressed.
+	pub fn attributes_iter(
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+	) -> impl Iterator<Item = (Vec<u8>, Vec<u8>)> {
+		Attribute::<T, I>::iter_prefix((collection, maybe_item, namespace.clone())).filter_map(
+			move |(key, (value, _))| {
+				let value = Self::read_attribute_value(
+					&collection,
+					maybe_item,
+					&namespace,
+					key.as_bounded_slice(),
+					value.into(),
+				)?;
+				Some((key.into(), value))
+			},
+		)
+	}
+
+	/// Get up to `limit` attributes of the item within the `namespace` whose keys start with the
+	/// `key_prefix`.
+	///
+	/// Works the same way as the `item_attributes()` does. Since the attributes are not stored in
+	/// the order of their keys, all the attributes within the `namespace` might be read, which is
+	/// bounded by the `T::MaxAttributesPerItem`.
+	pub fn item_attributes_filtered(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		namespace: AttributeNamespace<T::AccountId>,
+		key_prefix: Vec<u8>,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+	) -> (Vec<(Vec<u8>, Vec<u8>)>, Option<Vec<u8>>) {
+		let iter = match start_key {
+			Some(key) => {
+				let Ok(key) = BoundedVec::<u8, T::KeyLimit>::try_from(key) else {
+					return (Vec::new(), None)
+				};
+				let starting_raw_key =
+					Attribute::<T, I>::hashed_key_for((&collection, Some(item), &namespace, &key));
+				Attribute::<T, I>::iter_prefix_from(
+					(&collection, Some(item), &namespace),
+					starting_raw_key,
+				)
+			},
+			None => Attribute::<T, I>::iter_prefix((&collection, Some(item), &namespace)),
+		};
+
+		let mut iter =
+			iter.filter(|(key, _)| key.starts_with(&key_prefix))
+				.filter_map(|(key, (value, _))| {
+					let value = Self::read_attribute_value(
+						&collection,
+						Some(item),
+						&namespace,
+						key.as_bounded_slice(),
+						value.into(),
+					)?;
+					Some((key.into(), value))
+				});
+		let attributes: Vec<(Vec<u8>, Vec<u8>)> = iter.by_ref().take(limit as usize).collect();
+		let next_key = match iter.next() {
+			Some(_) => attributes.last().map(|(key, _)| key.clone()),
+			None => None,
+		};
+		(attributes, next_key)
+	}
+
+	/// Get up to `limit` attributes set by the owner of the `collection` for the collection
+	/// itself.
+	///
+	/// Works the same way as the `item_attributes()` does, including skipping the expired
+	/// attributes and decompressing the compressed values.
+	pub fn collection_attributes(
+		collection: T::CollectionId,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+	) -> (Vec<(Vec<u8>, Vec<u8>)>, Option<Vec<u8>>) {
+		let namespace = AttributeNamespace::CollectionOwner;
+		let iter = match start_key {
+			Some(key) => {
+				let Ok(key) = BoundedVec::<u8, T::KeyLimit>::try_from(key) else {
+					return (Vec::new(), None)
+				};
+				let starting_raw_key = Attribute::<T, I>::hashed_key_for((
+					&collection,
+					Option::<T::ItemId>::None,
+					&namespace,
+					&key,
+				));
+				Attribute::<T, I>::iter_prefix_from(
+					(&collection, Option::<T::ItemId>::None, &namespace),
+					starting_raw_key,
+				)
+			},
+			None =>
+				Attribute::<T, I>::iter_prefix((&collection, Option::<T::ItemId>::None, &namespace)),
+		};
+
+		let mut iter = iter.filter_map(|(key, (value, _))| {
+			let value = Self::read_attribute_value(
+				&collection,
+				None,
+				&namespace,
+				key.as_bounded_slice(),
+				value.into(),
+			)?;
+			Some((key.into(), value))
+		});
+		let attributes: Vec<(Vec<u8>, Vec<u8>)> = iter.by_ref().take(limit as usize).collect();
+		let next_key = match iter.next() {
+			Some(_) => attributes.last().map(|(key, _)| key.clone()),
+			None => None,
+		};
+		(attributes, next_key)
+	}
+
+	/// Get up to `limit` items of the `collection` owned by the `account`.
+	///
+	/// When `start` is provided, the items are returned starting right after that item. Along
+	/// with the items, the item to continue the iteration from is returned in case there are more
+	/// items left.
+	///
+	/// NOTE: the items are looked up within the `Account` reverse index, so the complexity is
+	/// proportional to the number of the `account`'s items within the `collection` rather than to
+	/// the size of the collection.
+	pub fn account_items(
+		account: T::AccountId,
+		collection: T::CollectionId,
+		start: Option<T::ItemId>,
+		limit: u32,
+	) -> (Vec<T::ItemId>, Option<T::ItemId>) {
+		let mut iter = match start {
+			Some(item) => {
+				let starting_raw_key =
+					Account::<T, I>::hashed_key_for((&account, &collection, &item));
+				Account::<T, I>::iter_key_prefix_from((&account, &collection), starting_raw_key)
+			},
+			None => Account::<T, I>::iter_key_prefix((&account, &collection)),
+		};
+
+		let items: Vec<T::ItemId> = iter.by_ref().take(limit as usize).collect();
+		let next_item = match iter.next() {
+			Some(_) => items.last().cloned(),
+			None => None,
+		};
+		(items, next_item)
+	}
+
+	/// Get up to `limit` values of the `key` attribute within the `namespace` across all the items
+	/// of the `collection`, along with the items holding them.
+	///
+	/// The items are paginated the same way as in the `account_items()`. The items that don't
+	/// hold the attribute or whose attribute has expired are skipped, the compressed values are
+	/// decompressed.
+	///
+	/// NOTE: the complexity is proportional to the number of the collection's items iterated over
+	/// until `limit` values are found, so it might be up to the size of the collection.
+	pub fn collection_namespace_attributes(
+		collection: T::CollectionId,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: Vec<u8>,
+		start: Option<T::ItemId>,
+		limit: u32,
+	) -> (Vec<(T::ItemId, Vec<u8>)>, Option<T::ItemId>) {
+		let Ok(key) = BoundedVec::<u8, T::KeyLimit>::try_from(key) else {
+			return (Vec::new(), None)
+		};
+		let items = match start {
+			Some(item) => {
+				let starting_raw_key = Item::<T, I>::hashed_key_for(&collection, &item);
+				Item::<T, I>::iter_key_prefix_from(&collection, starting_raw_key)
+			},
+			None => Item::<T, I>::iter_key_prefix(&collection),
+		};
+
+		let mut iter = items.filter_map(|item| {
+			let (value, _) = Attribute::<T, I>::get((&collection, Some(item), &namespace, &key))?;
+			let value = Self::read_attribute_value(
+				&collection,
+				Some(item),
+				&namespace,
+				key.as_bounded_slice(),
+				value.into(),
+			)?;
+			Some((item, value))
+		});
+		let values: Vec<(T::ItemId, Vec<u8>)> = iter.by_ref().take(limit as usize).collect();
+		let next_item = match iter.next() {
+			Some(_) => values.last().map(|(item, _)| *item),
+			None => None,
+		};
+		(values, next_item)
+	}
+
+	/// Construct the hierarchical attribute key, e.g. `stats/strength`, by joining the
+	/// `segments` with the `well_known_keys::PATH_SEPARATOR`.
+	///
+	/// Fails if any of the segments is empty or contains the separator, as well as when the
+	/// resulting key exceeds the `T::KeyLimit`.
+	pub fn construct_path_key(
+		segments: &[&[u8]],
+	) -> Result<BoundedVec<u8, T::KeyLimit>, DispatchError> {
+		ensure!(!segments.is_empty(), Error::<T, I>::InvalidPathSegment);
+		ensure!(
+			segments.iter().all(|segment| {
+				!segment.is_empty() && !segment.contains(&well_known_keys::PATH_SEPARATOR)
+			}),
+			Error::<T, I>::InvalidPathSegment
+		);
+		let key = segments.join(&well_known_keys::PATH_SEPARATOR);
+		key.try_into().map_err(|_| Error::<T, I>::PathTooLong.into())
+	}
+
 	#[cfg(any(test, feature = "runtime-benchmarks"))]
 	pub fn set_next_id(id: T::CollectionId) {
 		NextCollectionId::<T, I>::set(Some(id));
@@ -0,0 +1,44 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compression of the attribute values.
+
+use sp_std::prelude::*;
+
+/// Compresses the values of the attributes set with `set_compressed_attribute`.
+///
+/// Both the compression and the decompression happen within the runtime, so the
+/// implementations have to be deterministic and `no_std` compatible.
+pub trait AttributeCompressor {
+	/// Compresses the `value`, `None` means the value can't be compressed.
+	fn compress(value: &[u8]) -> Option<Vec<u8>>;
+
+	/// Decompresses the `value`, `None` means it's malformed or would exceed `max_len` bytes
+	/// once decompressed.
+	fn decompress(value: &[u8], max_len: usize) -> Option<Vec<u8>>;
+}
+
+/// No compression is supported, so the compressed attributes can't be set.
+impl AttributeCompressor for () {
+	fn compress(_value: &[u8]) -> Option<Vec<u8>> {
+		None
+	}
+
+	fn decompress(_value: &[u8], _max_len: usize) -> Option<Vec<u8>> {
+		None
+	}
+}
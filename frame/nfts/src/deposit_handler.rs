@@ -0,0 +1,170 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Handling of the deposits reserved for the attributes.
+
+use crate::*;
+use frame_support::{
+	dispatch::DispatchResult,
+	traits::{tokens::fungibles, Get},
+};
+use sp_std::marker::PhantomData;
+
+/// Reserves and returns the deposits of the attributes.
+///
+/// NOTE: the deposits paid by the collection's owner within the `CollectionOwner` namespace are
+/// also accounted within the collection's `owner_deposit`, which is moved along with the
+/// collection's ownership and returned on destroy using the `T::Currency`. That's only the case
+/// for the collections whose deposits are reserved using the `T::Currency`, see
+/// `uses_currency()`, the owner's deposits of the other collections are tracked like the deposits
+/// of any other account.
+///
+/// NOTE: the `fungible::MutateHold` doesn't support the hold reasons yet, so the attribute
+/// deposits can't be told apart from the other reserves of the account on-chain.
+pub trait DepositHandler<T: Config<I>, I: 'static = ()> {
+	/// Reserves the `amount` from `who` for the attribute of the `collection`.
+	fn reserve(
+		collection: &T::CollectionId,
+		who: &T::AccountId,
+		amount: DepositBalanceOf<T, I>,
+	) -> DispatchResult;
+
+	/// Returns the `amount` previously reserved from `who` for the attribute of the `collection`.
+	///
+	/// Returns the part of the `amount` that couldn't be returned, e.g. because the reserve of
+	/// `who` was slashed or the account was reaped in the meantime.
+	fn unreserve(
+		collection: &T::CollectionId,
+		who: &T::AccountId,
+		amount: DepositBalanceOf<T, I>,
+	) -> DepositBalanceOf<T, I>;
+
+	/// Whether the deposits of the `collection` are reserved using the `T::Currency`.
+	fn uses_currency(_collection: &T::CollectionId) -> bool {
+		true
+	}
+}
+
+/// Provides the asset the attribute deposits of a collection are paid in.
+pub trait DepositAsset<CollectionId, AssetId> {
+	/// Returns the asset the deposits of the `collection` are paid in, or `None` if those are
+	/// reserved using the `T::Currency`.
+	///
+	/// NOTE: the asset mustn't change while the collection holds any deposits, those would be
+	/// returned in the wrong asset otherwise.
+	fn deposit_asset(collection: &CollectionId) -> Option<AssetId>;
+}
+
+/// Handles the attribute deposits that couldn't be returned to their depositors.
+pub trait OnLostDeposit<T: Config<I>, I: 'static = ()> {
+	/// Called when the `amount` reserved from `depositor` for the attribute of the `collection`
+	/// couldn't be returned on clear.
+	///
+	/// The implementations may e.g. compensate the collection's owner or the treasury.
+	fn on_lost_deposit(
+		collection: &T::CollectionId,
+		depositor: &T::AccountId,
+		amount: DepositBalanceOf<T, I>,
+	);
+}
+
+impl<T: Config<I>, I: 'static> OnLostDeposit<T, I> for () {
+	fn on_lost_deposit(
+		_collection: &T::CollectionId,
+		_depositor: &T::AccountId,
+		_amount: DepositBalanceOf<T, I>,
+	) {
+	}
+}
+
+/// Reserves the attributes' deposits using the `T::Currency`, regardless of the collection.
+pub struct CurrencyDepositHandler;
+
+impl<T: Config<I>, I: 'static> DepositHandler<T, I> for CurrencyDepositHandler {
+	fn reserve(
+		_collection: &T::CollectionId,
+		who: &T::AccountId,
+		amount: DepositBalanceOf<T, I>,
+	) -> DispatchResult {
+		T::Currency::reserve(who, amount)
+	}
+
+	fn unreserve(
+		_collection: &T::CollectionId,
+		who: &T::AccountId,
+		amount: DepositBalanceOf<T, I>,
+	) -> DepositBalanceOf<T, I> {
+		T::Currency::unreserve(who, amount)
+	}
+}
+
+/// Takes the attributes' deposits in the asset provided by the `Asset` for the collection,
+/// falling back to the `T::Currency` for the collections without one.
+///
+/// The `Assets` can't hold the funds, so the deposits are transferred to the `Holder` account and
+/// transferred back when returned. The `Holder` has to exist beforehand for the deposits below
+/// the asset's min balance to be accepted.
+pub struct FungiblesDepositHandler<Assets, Asset, Holder>(PhantomData<(Assets, Asset, Holder)>);
+
+impl<T, I, Assets, Asset, Holder> DepositHandler<T, I>
+	for FungiblesDepositHandler<Assets, Asset, Holder>
+where
+	T: Config<I>,
+	I: 'static,
+	Assets: fungibles::Transfer<T::AccountId, Balance = DepositBalanceOf<T, I>>,
+	Asset: DepositAsset<T::CollectionId, Assets::AssetId>,
+	Holder: Get<T::AccountId>,
+{
+	fn reserve(
+		collection: &T::CollectionId,
+		who: &T::AccountId,
+		amount: DepositBalanceOf<T, I>,
+	) -> DispatchResult {
+		let Some(asset) = Asset::deposit_asset(collection) else {
+			return <CurrencyDepositHandler as DepositHandler<T, I>>::reserve(
+				collection, who, amount,
+			)
+		};
+		if amount.is_zero() {
+			return Ok(())
+		}
+		Assets::transfer(asset, who, &Holder::get(), amount, true).map(|_| ())
+	}
+
+	fn unreserve(
+		collection: &T::CollectionId,
+		who: &T::AccountId,
+		amount: DepositBalanceOf<T, I>,
+	) -> DepositBalanceOf<T, I> {
+		let Some(asset) = Asset::deposit_asset(collection) else {
+			return <CurrencyDepositHandler as DepositHandler<T, I>>::unreserve(
+				collection, who, amount,
+			)
+		};
+		if amount.is_zero() {
+			return amount
+		}
+		match Assets::transfer(asset, &Holder::get(), who, amount, false) {
+			Ok(_) => Zero::zero(),
+			Err(_) => amount,
+		}
+	}
+
+	fn uses_currency(collection: &T::CollectionId) -> bool {
+		Asset::deposit_asset(collection).is_none()
+	}
+}
@@ -16,7 +16,11 @@
 // limitations under the License.
 
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::{with_transaction, TransactionOutcome},
+	BoundedSlice,
+};
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	pub(crate) fn do_set_attribute(
@@ -27,27 +31,110 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		key: BoundedVec<u8, T::KeyLimit>,
 		value: BoundedVec<u8, T::ValueLimit>,
 		depositor: T::AccountId,
+	) -> DispatchResult {
+		Self::do_set_typed_attribute(
+			origin,
+			collection,
+			maybe_item,
+			namespace,
+			key,
+			value,
+			AttributeValueType::Raw,
+			depositor,
+		)
+	}
+
+	pub(crate) fn do_set_typed_attribute(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+		value: BoundedVec<u8, T::ValueLimit>,
+		value_type: AttributeValueType,
+		depositor: T::AccountId,
 	) -> DispatchResult {
 		ensure!(
 			Self::is_pallet_feature_enabled(PalletFeature::Attributes),
 			Error::<T, I>::MethodDisabled
 		);
+		// the empty key is reserved for the metadata
+		ensure!(!key.is_empty(), Error::<T, I>::EmptyKey);
+		ensure!(
+			value.len() >= T::MinAttributeValueLen::get() as usize,
+			Error::<T, I>::ValueTooShort
+		);
 
 		let mut collection_details =
 			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let collection_config = Self::ensure_attributes_settable(
+			&origin,
+			&collection,
+			&collection_details,
+			maybe_item,
+			&namespace,
+		)?;
+		Self::ensure_attribute_writable(
+			&collection,
+			maybe_item,
+			&namespace,
+			&key,
+			&value,
+			value_type,
+		)?;
+
+		// the item's owner might pay the deposits of the delegated accounts
+		let sponsor = Self::attribute_deposit_sponsor(&collection, maybe_item, &namespace);
+		let depositor = sponsor.clone().unwrap_or(depositor);
+
+		let Some((to_reserve, to_unreserve)) = Self::write_attribute(
+			&mut collection_details,
+			&collection_config,
+			collection,
+			maybe_item,
+			&namespace,
+			key,
+			value,
+			value_type,
+			&depositor,
+			sponsor.is_some(),
+		)?
+		else {
+			return Ok(())
+		};
+		Self::settle_attribute_deposit(&collection, &depositor, to_reserve, to_unreserve)?;
+		Collection::<T, I>::insert(collection, &collection_details);
+		Ok(())
+	}
+
+	/// Ensures the `origin` is allowed to set the attributes of the `collection` or of its item
+	/// within the `namespace` and that those aren't locked.
+	///
+	/// Returns the collection's config.
+	fn ensure_attributes_settable(
+		origin: &T::AccountId,
+		collection: &T::CollectionId,
+		collection_details: &CollectionDetailsFor<T, I>,
+		maybe_item: Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+	) -> Result<CollectionConfigFor<T, I>, DispatchError> {
+		// not every namespace check loads the item, and its config might outlive it
+		if let Some(item) = maybe_item {
+			ensure!(Item::<T, I>::contains_key(collection, &item), Error::<T, I>::UnknownItem);
+		}
 
 		ensure!(
 			Self::is_valid_namespace(
-				&origin,
-				&namespace,
-				&collection,
+				origin,
+				namespace,
+				collection,
 				&collection_details.owner,
 				&maybe_item,
 			)?,
 			Error::<T, I>::NoPermission
 		);
 
-		let collection_config = Self::get_collection_config(&collection)?;
+		let collection_config = Self::get_collection_config(collection)?;
 		// for the `CollectionOwner` namespace we need to check if the collection/item is not locked
 		match namespace {
 			AttributeNamespace::CollectionOwner => match maybe_item {
@@ -58,36 +145,85 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					)
 				},
 				Some(item) => {
-					let maybe_is_locked = Self::get_item_config(&collection, &item)
+					let maybe_is_locked = Self::get_item_config(collection, &item)
 						.map(|c| c.has_disabled_setting(ItemSetting::UnlockedAttributes))?;
 					ensure!(!maybe_is_locked, Error::<T, I>::LockedItemAttributes);
 				},
 			},
 			_ => (),
 		}
+		Ok(collection_config)
+	}
 
-		let attribute = Attribute::<T, I>::get((collection, maybe_item, &namespace, &key));
+	/// Ensures the `key` attribute can be set to the `value`.
+	fn ensure_attribute_writable(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: &BoundedVec<u8, T::KeyLimit>,
+		value: &BoundedVec<u8, T::ValueLimit>,
+		value_type: AttributeValueType,
+	) -> DispatchResult {
+		Self::ensure_attribute_key_unlocked(collection, maybe_item, namespace, key)?;
+		Self::ensure_attribute_key_not_reserved(namespace, key)?;
+		// the manifest holds no value of its own, the compressed value is checked before the
+		// compression
+		if !matches!(value_type, AttributeValueType::ChunkManifest | AttributeValueType::Compressed)
+		{
+			Self::ensure_schema_satisfied(collection, namespace, key, value)?;
+		}
+		Ok(())
+	}
+
+	/// Writes the attribute once all the checks have passed, updating the `collection_details`
+	/// in place.
+	///
+	/// The deposit of the previous depositor is returned right away, while the changes of the
+	/// `depositor`'s deposit are returned as the amounts to reserve and to unreserve, so the
+	/// callers could settle them at once. Returns `None` if setting the attribute wouldn't change
+	/// anything, in which case nothing gets written.
+	fn write_attribute(
+		collection_details: &mut CollectionDetailsFor<T, I>,
+		collection_config: &CollectionConfigFor<T, I>,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+		value: BoundedVec<u8, T::ValueLimit>,
+		value_type: AttributeValueType,
+		depositor: &T::AccountId,
+		is_sponsored: bool,
+	) -> Result<Option<(DepositBalanceOf<T, I>, DepositBalanceOf<T, I>)>, DispatchError> {
+		let attribute = Attribute::<T, I>::get((collection, maybe_item, namespace, &key));
 		let attribute_exists = attribute.is_some();
 		if !attribute_exists {
 			collection_details.attributes.saturating_inc();
+			Self::increase_item_attributes(&collection, maybe_item)?;
+			Self::note_delegate_attributes(&collection, maybe_item, namespace, 1, true);
+			Self::note_namespace_attributes(&collection, namespace, 1, true);
 		}
 
-		let old_deposit =
-			attribute.map_or(AttributeDeposit { account: None, amount: Zero::zero() }, |m| m.1);
+		let (old_value, old_deposit) = match attribute {
+			Some((old_value, old_deposit)) => (Some(old_value), old_deposit),
+			None => (None, AttributeDeposit { account: None, amount: Zero::zero() }),
+		};
 
 		let mut deposit = Zero::zero();
 		// disabled DepositRequired setting only affects the CollectionOwner namespace
 		if collection_config.is_setting_enabled(CollectionSetting::DepositRequired) ||
-			namespace != AttributeNamespace::CollectionOwner
+			*namespace != AttributeNamespace::CollectionOwner
 		{
 			deposit = T::DepositPerByte::get()
 				.saturating_mul(((key.len() + value.len()) as u32).into())
 				.saturating_add(T::AttributeDepositBase::get());
 		}
 
-		let is_collection_owner_namespace = namespace == AttributeNamespace::CollectionOwner;
-		let is_depositor_collection_owner =
-			is_collection_owner_namespace && collection_details.owner == depositor;
+		let is_collection_owner_namespace = *namespace == AttributeNamespace::CollectionOwner;
+		// the owner's deposits are only accounted within the `owner_deposit` when those are
+		// reserved using the `T::Currency`
+		let is_depositor_collection_owner = is_collection_owner_namespace &&
+			collection_details.owner == *depositor &&
+			T::AttributeDepositHandler::uses_currency(&collection);
 
 		// NOTE: in the CollectionOwner namespace if the depositor is `None` that means the deposit
 		// was paid by the collection's owner.
@@ -97,21 +233,58 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			} else {
 				old_deposit.account
 			};
-		let depositor_has_changed = old_depositor != Some(depositor.clone());
+		let depositor_has_changed = old_depositor.as_ref() != Some(depositor);
+
+		// setting the same value again wouldn't change anything, so nothing gets written
+		let storage_key = (&collection, maybe_item, namespace, &key);
+		if old_value.as_ref() == Some(&value) &&
+			!depositor_has_changed &&
+			deposit == old_deposit.amount &&
+			AttributeValueTypeOf::<T, I>::get(storage_key).unwrap_or_default() == value_type &&
+			!AttributeExpiryOf::<T, I>::contains_key(storage_key)
+		{
+			return Ok(None)
+		}
+		Self::note_attribute_writes(&collection, 1)?;
 
 		// NOTE: when we transfer an item, we don't move attributes in the ItemOwner namespace.
 		// When the new owner updates the same attribute, we will update the depositor record
 		// and return the deposit to the previous owner.
-		if depositor_has_changed {
+		let deposit_change = if depositor_has_changed {
 			if let Some(old_depositor) = old_depositor {
-				T::Currency::unreserve(&old_depositor, old_deposit.amount);
+				Self::return_attribute_deposit(&collection, &old_depositor, old_deposit.amount);
+				Self::note_attribute_deposit_change(
+					collection,
+					maybe_item,
+					namespace,
+					&key,
+					old_depositor,
+					old_deposit.amount,
+					Zero::zero(),
+				);
 			}
-			T::Currency::reserve(&depositor, deposit)?;
-		} else if deposit > old_deposit.amount {
-			T::Currency::reserve(&depositor, deposit - old_deposit.amount)?;
-		} else if deposit < old_deposit.amount {
-			T::Currency::unreserve(&depositor, old_deposit.amount - deposit);
-		}
+			Self::note_attribute_deposit_change(
+				collection,
+				maybe_item,
+				namespace,
+				&key,
+				depositor.clone(),
+				Zero::zero(),
+				deposit,
+			);
+			(deposit, Zero::zero())
+		} else {
+			Self::note_attribute_deposit_change(
+				collection,
+				maybe_item,
+				namespace,
+				&key,
+				depositor.clone(),
+				old_deposit.amount,
+				deposit,
+			);
+			(deposit, old_deposit.amount)
+		};
 
 		if is_depositor_collection_owner {
 			if !depositor_has_changed {
@@ -122,15 +295,198 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		let new_deposit_owner = match is_depositor_collection_owner {
 			true => None,
-			false => Some(depositor),
+			false => Some(depositor.clone()),
 		};
 		Attribute::<T, I>::insert(
-			(&collection, maybe_item, &namespace, &key),
+			(&collection, maybe_item, namespace, &key),
 			(&value, AttributeDeposit { account: new_deposit_owner, amount: deposit }),
 		);
+		Self::set_attribute_value_type(&collection, maybe_item, namespace, &key, value_type);
+		AttributeExpiryOf::<T, I>::remove((&collection, maybe_item, namespace, &key));
+		Self::note_attribute_last_set(&collection, maybe_item, namespace, &key);
+
+		if let (true, Some(item), AttributeNamespace::Account(delegate)) =
+			(is_sponsored, maybe_item, namespace)
+		{
+			Self::deposit_event(Event::AttributeDepositSponsored {
+				collection,
+				item,
+				key: key.clone(),
+				delegate: delegate.clone(),
+				sponsor: depositor.clone(),
+			});
+		}
+		Self::deposit_event(Event::AttributeSet {
+			collection,
+			maybe_item,
+			key,
+			value,
+			namespace: namespace.clone(),
+			old_value,
+		});
+		Ok(Some(deposit_change))
+	}
+
+	/// Reserves the `to_reserve` and returns the `to_unreserve` part of the `depositor`'s
+	/// deposit, only the difference between the two is actually moved.
+	fn settle_attribute_deposit(
+		collection: &T::CollectionId,
+		depositor: &T::AccountId,
+		to_reserve: DepositBalanceOf<T, I>,
+		to_unreserve: DepositBalanceOf<T, I>,
+	) -> DispatchResult {
+		if to_reserve > to_unreserve {
+			T::AttributeDepositHandler::reserve(collection, depositor, to_reserve - to_unreserve)?;
+		} else if to_reserve < to_unreserve {
+			Self::return_attribute_deposit(collection, depositor, to_unreserve - to_reserve);
+		}
+		Ok(())
+	}
+
+	/// Returns the `amount` of the attribute deposit to the `depositor`, passing the part that
+	/// couldn't be returned to the `T::OnLostDeposit`.
+	///
+	/// Returns the amount actually returned.
+	pub(crate) fn return_attribute_deposit(
+		collection: &T::CollectionId,
+		depositor: &T::AccountId,
+		amount: DepositBalanceOf<T, I>,
+	) -> DepositBalanceOf<T, I> {
+		let lost = T::AttributeDepositHandler::unreserve(collection, depositor, amount);
+		if !lost.is_zero() {
+			T::OnLostDeposit::on_lost_deposit(collection, depositor, lost);
+		}
+		amount.saturating_sub(lost)
+	}
+
+	/// Returns the item's owner if they pay the deposits for the attributes set within the
+	/// `namespace` of a delegated account.
+	fn attribute_deposit_sponsor(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+	) -> Option<T::AccountId> {
+		let (AttributeNamespace::Account(delegate), Some(item)) = (namespace, maybe_item) else {
+			return None
+		};
+		let config = ItemConfigOf::<T, I>::get(collection, item)?;
+		if config.is_setting_enabled(ItemSetting::DelegatesPayDeposit) {
+			return None
+		}
+		// the collection's attribute editors aren't approved by the item's owner
+		if !ItemAttributesApprovalsOf::<T, I>::get(collection, item).contains(delegate) {
+			return None
+		}
+		Self::owner(*collection, item)
+	}
+
+	pub(crate) fn do_set_delegate_attributes_sponsorship(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		sponsor: bool,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Attributes),
+			Error::<T, I>::MethodDisabled
+		);
+
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(origin == details.owner, Error::<T, I>::NoPermission);
+
+		let mut config = Self::get_item_config(&collection, &item)?;
+		if sponsor {
+			config.disable_setting(ItemSetting::DelegatesPayDeposit);
+		} else {
+			config.enable_setting(ItemSetting::DelegatesPayDeposit);
+		}
+		ItemConfigOf::<T, I>::insert(&collection, &item, config);
+
+		Self::deposit_event(Event::DelegateAttributesSponsorshipSet { collection, item, sponsor });
+		Ok(())
+	}
+
+	/// Sets multiple attributes of the same `collection`/`maybe_item` within the `namespace`.
+	///
+	/// Every attribute is written the same way as by `do_set_typed_attribute()`, but the
+	/// collection details are read and written once, the namespace is validated once and the
+	/// `depositor`'s deposit is reserved or unreserved once for the whole batch.
+	pub(crate) fn do_set_attributes(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		attributes: Vec<(BoundedVec<u8, T::KeyLimit>, BoundedVec<u8, T::ValueLimit>)>,
+		depositor: T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Attributes),
+			Error::<T, I>::MethodDisabled
+		);
+		ensure!(
+			attributes.len() <= T::MaxAttributesPerCall::get() as usize,
+			Error::<T, I>::MaxAttributesLimitReached
+		);
+		ensure!(attributes.iter().all(|(key, _)| !key.is_empty()), Error::<T, I>::EmptyKey);
+		ensure!(
+			attributes
+				.iter()
+				.all(|(_, value)| value.len() >= T::MinAttributeValueLen::get() as usize),
+			Error::<T, I>::ValueTooShort
+		);
+
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let collection_config = Self::ensure_attributes_settable(
+			&origin,
+			&collection,
+			&collection_details,
+			maybe_item,
+			&namespace,
+		)?;
+		for (key, value) in attributes.iter() {
+			Self::ensure_attribute_writable(
+				&collection,
+				maybe_item,
+				&namespace,
+				key,
+				value,
+				AttributeValueType::Raw,
+			)?;
+		}
+
+		// same as in `do_set_typed_attribute()`
+		let sponsor = Self::attribute_deposit_sponsor(&collection, maybe_item, &namespace);
+		let depositor = sponsor.clone().unwrap_or(depositor);
+
+		// the depositor's deposit changes are aggregated and applied once at the end
+		let mut to_reserve: DepositBalanceOf<T, I> = Zero::zero();
+		let mut to_unreserve: DepositBalanceOf<T, I> = Zero::zero();
+		let mut written = false;
+		for (key, value) in attributes {
+			if let Some((reserve, unreserve)) = Self::write_attribute(
+				&mut collection_details,
+				&collection_config,
+				collection,
+				maybe_item,
+				&namespace,
+				key,
+				value,
+				AttributeValueType::Raw,
+				&depositor,
+				sponsor.is_some(),
+			)? {
+				to_reserve.saturating_accrue(reserve);
+				to_unreserve.saturating_accrue(unreserve);
+				written = true;
+			}
+		}
+		if !written {
+			return Ok(())
+		}
 
+		Self::settle_attribute_deposit(&collection, &depositor, to_reserve, to_unreserve)?;
 		Collection::<T, I>::insert(collection, &collection_details);
-		Self::deposit_event(Event::AttributeSet { collection, maybe_item, key, value, namespace });
 		Ok(())
 	}
 
@@ -141,30 +497,142 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		namespace: AttributeNamespace<T::AccountId>,
 		key: BoundedVec<u8, T::KeyLimit>,
 		value: BoundedVec<u8, T::ValueLimit>,
+		preserve_deposit: bool,
+		new_deposit: Option<DepositBalanceOf<T, I>>,
 	) -> DispatchResult {
+		ensure!(!key.is_empty(), Error::<T, I>::EmptyKey);
+
 		let mut collection_details =
 			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 
+		// the new deposit paid by the collection's owner within the CollectionOwner namespace is
+		// accounted within the collection's `owner_deposit`, same as in `write_attribute()`
+		let is_owner_deposit = new_deposit.is_some() &&
+			namespace == AttributeNamespace::CollectionOwner &&
+			set_as.as_ref() == Some(&collection_details.owner) &&
+			T::AttributeDepositHandler::uses_currency(&collection);
+		let record_as = if is_owner_deposit { None } else { set_as.clone() };
+
 		let attribute = Attribute::<T, I>::get((collection, maybe_item, &namespace, &key));
-		if let Some((_, deposit)) = attribute {
-			if deposit.account != set_as && deposit.amount != Zero::zero() {
-				if let Some(deposit_account) = deposit.account {
-					T::Currency::unreserve(&deposit_account, deposit.amount);
+		let mut deposit_amount = Zero::zero();
+		let mut returned_to_depositor = Zero::zero();
+		let mut reassigned_from = None;
+		let old_value = match attribute {
+			Some((old_value, deposit)) => {
+				if deposit.account != record_as {
+					reassigned_from = Some((deposit.account.clone(), deposit.amount));
+				} else {
+					returned_to_depositor = deposit.amount;
+				}
+				if deposit.account == record_as && preserve_deposit {
+					// NOTE: when `set_as` is `None` in the `CollectionOwner` namespace, the
+					// preserved deposit remains tracked within the collection's `owner_deposit`.
+					deposit_amount = deposit.amount;
+				} else if !deposit.amount.is_zero() {
+					// the new record doesn't account for the old deposit, so it has to be returned
+					// even if it was paid by `set_as`
+					match deposit.account {
+						Some(deposit_account) => {
+							Self::return_attribute_deposit(
+								&collection,
+								&deposit_account,
+								deposit.amount,
+							);
+						},
+						None if namespace == AttributeNamespace::CollectionOwner => {
+							collection_details.owner_deposit.saturating_reduce(deposit.amount);
+							Self::return_attribute_deposit(
+								&collection,
+								&collection_details.owner,
+								deposit.amount,
+							);
+						},
+						_ => (),
+					}
 				}
+				Some(old_value)
+			},
+			None => {
+				collection_details.attributes.saturating_inc();
+				Self::increase_item_attributes(&collection, maybe_item)?;
+				Self::note_delegate_attributes(&collection, maybe_item, &namespace, 1, true);
+				Self::note_namespace_attributes(&collection, &namespace, 1, true);
+				None
+			},
+		};
+
+		if let (Some(amount), Some(depositor)) = (new_deposit, &set_as) {
+			T::AttributeDepositHandler::reserve(&collection, depositor, amount)?;
+			if is_owner_deposit {
+				collection_details.owner_deposit.saturating_accrue(amount);
 			}
-		} else {
-			collection_details.attributes.saturating_inc();
+			deposit_amount = amount;
+			Self::note_attribute_deposit_change(
+				collection,
+				maybe_item,
+				&namespace,
+				&key,
+				depositor.clone(),
+				returned_to_depositor,
+				amount,
+			);
 		}
 
 		Attribute::<T, I>::insert(
 			(&collection, maybe_item, &namespace, &key),
-			(&value, AttributeDeposit { account: set_as, amount: Zero::zero() }),
+			(&value, AttributeDeposit { account: record_as, amount: deposit_amount }),
 		);
+		if let Some((from, deposit)) = reassigned_from {
+			Self::deposit_event(Event::AttributeDepositReassigned {
+				collection,
+				maybe_item,
+				namespace: namespace.clone(),
+				key: key.clone(),
+				from,
+				to: set_as,
+				deposit,
+			});
+		}
+		AttributeValueTypeOf::<T, I>::remove((&collection, maybe_item, &namespace, &key));
+		AttributeExpiryOf::<T, I>::remove((&collection, maybe_item, &namespace, &key));
+		Self::note_attribute_last_set(&collection, maybe_item, &namespace, &key);
 		Collection::<T, I>::insert(collection, &collection_details);
-		Self::deposit_event(Event::AttributeSet { collection, maybe_item, key, value, namespace });
+		Self::deposit_event(Event::AttributeSet {
+			collection,
+			maybe_item,
+			key,
+			value,
+			namespace,
+			old_value,
+		});
 		Ok(())
 	}
 
+	/// Same as `do_force_set_attribute()`, but reserves the `amount` from `set_as` as the
+	/// attribute's deposit.
+	///
+	/// The existing deposit is returned first, even if it was paid by `set_as`.
+	pub(crate) fn do_force_set_attribute_with_deposit(
+		set_as: T::AccountId,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+		value: BoundedVec<u8, T::ValueLimit>,
+		amount: DepositBalanceOf<T, I>,
+	) -> DispatchResult {
+		Self::do_force_set_attribute(
+			Some(set_as),
+			collection,
+			maybe_item,
+			namespace,
+			key,
+			value,
+			false,
+			Some(amount),
+		)
+	}
+
 	pub(crate) fn do_set_attributes_pre_signed(
 		origin: T::AccountId,
 		data: PreSignedAttributesOf<T, I>,
@@ -203,19 +671,214 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			_ => return Err(Error::<T, I>::WrongNamespace.into()),
 		}
 
-		for (key, value) in attributes {
+		let attributes = attributes
+			.into_iter()
+			.map(|(key, value)| {
+				Ok((Self::construct_attribute_key(key)?, Self::construct_attribute_value(value)?))
+			})
+			.collect::<Result<Vec<_>, DispatchError>>()?;
+		Self::do_set_attributes(
+			signer,
+			collection,
+			Some(item),
+			namespace.clone(),
+			attributes,
+			origin,
+		)?;
+		Self::deposit_event(Event::PreSignedAttributesSet { collection, item, namespace });
+		Ok(())
+	}
+
+	/// Sets the attribute whose value doesn't fit into `T::ValueLimit` by storing every chunk of
+	/// the value as a separate attribute. The `key` attribute holds the number of chunks.
+	///
+	/// The chunks left from the previous value of the attribute are cleared.
+	pub(crate) fn do_set_large_attribute(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+		chunks: Vec<BoundedVec<u8, T::ValueLimit>>,
+		depositor: T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			!chunks.is_empty() && chunks.len() <= T::MaxAttributeChunks::get() as usize,
+			Error::<T, I>::InvalidChunksNumber
+		);
+		let chunks_count = chunks.len() as u32;
+		let old_chunks_count =
+			Self::attribute_chunks_count(&collection, maybe_item, &namespace, &key).unwrap_or(0);
+
+		for (index, chunk) in chunks.into_iter().enumerate() {
 			Self::do_set_attribute(
-				signer.clone(),
+				origin.clone(),
 				collection,
-				Some(item),
+				maybe_item,
 				namespace.clone(),
-				Self::construct_attribute_key(key)?,
-				Self::construct_attribute_value(value)?,
-				origin.clone(),
+				Self::attribute_chunk_key(&key, index as u32)?,
+				chunk,
+				depositor.clone(),
 			)?;
 		}
-		Self::deposit_event(Event::PreSignedAttributesSet { collection, item, namespace });
-		Ok(())
+		for index in chunks_count..old_chunks_count {
+			Self::do_clear_attribute(
+				Some(origin.clone()),
+				collection,
+				maybe_item,
+				namespace.clone(),
+				Self::attribute_chunk_key(&key, index)?,
+			)?;
+		}
+
+		let manifest = BoundedVec::try_from(chunks_count.encode())
+			.map_err(|_| Error::<T, I>::IncorrectData)?;
+		Self::do_set_typed_attribute(
+			origin,
+			collection,
+			maybe_item,
+			namespace,
+			key,
+			manifest,
+			AttributeValueType::ChunkManifest,
+			depositor,
+		)
+	}
+
+	/// Clears the attribute set by `do_set_large_attribute()` along with all its chunks.
+	pub(crate) fn do_clear_large_attribute(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+	) -> DispatchResult {
+		let chunks_count = Self::attribute_chunks_count(&collection, maybe_item, &namespace, &key)
+			.ok_or(Error::<T, I>::AttributeNotFound)?;
+
+		for index in 0..chunks_count {
+			let chunk_key = Self::attribute_chunk_key(&key, index)?;
+			// the chunk might have been cleared on its own
+			if Attribute::<T, I>::contains_key((&collection, maybe_item, &namespace, &chunk_key)) {
+				Self::do_clear_attribute(
+					maybe_check_owner.clone(),
+					collection,
+					maybe_item,
+					namespace.clone(),
+					chunk_key,
+				)?;
+			}
+		}
+		Self::ensure_attribute_key_unlocked(&collection, maybe_item, &namespace, &key)?;
+		Self::remove_attribute(maybe_check_owner, collection, maybe_item, namespace, key)
+	}
+
+	/// Returns the key of the large attribute's chunk with the `index`.
+	pub(crate) fn attribute_chunk_key(
+		key: &BoundedVec<u8, T::KeyLimit>,
+		index: u32,
+	) -> Result<BoundedVec<u8, T::KeyLimit>, DispatchError> {
+		let mut chunk_key = key.to_vec();
+		chunk_key.extend(index.to_le_bytes());
+		Self::construct_attribute_key(chunk_key)
+	}
+
+	/// Sets the attribute whose value is compressed with the `T::AttributeCompressor`, so the
+	/// deposit is only taken for the compressed value.
+	///
+	/// The uncompressed `value` is limited to what `do_set_large_attribute()` could store.
+	pub(crate) fn do_set_compressed_attribute(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+		value: Vec<u8>,
+		depositor: T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			value.len() <= Self::max_uncompressed_value_len(),
+			Error::<T, I>::CompressedValueTooLong
+		);
+		// the schema constrains the actual value rather than its compressed form
+		match BoundedVec::try_from(value.clone()) {
+			Ok(value) => Self::ensure_schema_satisfied(&collection, &namespace, &key, &value)?,
+			Err(_) => ensure!(
+				namespace != AttributeNamespace::CollectionOwner ||
+					!AttributeSchemaOf::<T, I>::contains_key(&collection, &key),
+				Error::<T, I>::SchemaViolation
+			),
+		}
+
+		let compressed = T::AttributeCompressor::compress(&value)
+			.ok_or(Error::<T, I>::CompressionUnsupported)?;
+		let compressed =
+			BoundedVec::try_from(compressed).map_err(|_| Error::<T, I>::CompressedValueTooLong)?;
+		Self::do_set_typed_attribute(
+			origin,
+			collection,
+			maybe_item,
+			namespace,
+			key,
+			compressed,
+			AttributeValueType::Compressed,
+			depositor,
+		)
+	}
+
+	/// Returns the attribute's `value` the way every query exposes it: `None` once the attribute
+	/// has expired, and decompressed if it was set with `do_set_compressed_attribute()`.
+	pub(crate) fn read_attribute_value(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: BoundedSlice<u8, T::KeyLimit>,
+		value: Vec<u8>,
+	) -> Option<Vec<u8>> {
+		let expiry = AttributeExpiryOf::<T, I>::get((collection, maybe_item, namespace, key));
+		if Self::has_expired(expiry) {
+			return None
+		}
+		Self::decompress_attribute_value(collection, maybe_item, namespace, key, value)
+	}
+
+	/// Returns the attribute's `value` decompressed if it was set with
+	/// `do_set_compressed_attribute()`, or as is otherwise.
+	fn decompress_attribute_value(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: BoundedSlice<u8, T::KeyLimit>,
+		value: Vec<u8>,
+	) -> Option<Vec<u8>> {
+		match AttributeValueTypeOf::<T, I>::get((collection, maybe_item, namespace, key)) {
+			Some(AttributeValueType::Compressed) =>
+				T::AttributeCompressor::decompress(&value, Self::max_uncompressed_value_len()),
+			_ => Some(value),
+		}
+	}
+
+	/// The max length of an attribute's value before the compression.
+	fn max_uncompressed_value_len() -> usize {
+		(T::ValueLimit::get() as usize).saturating_mul(T::MaxAttributeChunks::get() as usize)
+	}
+
+	/// Returns the number of chunks of the large attribute, if the `key` attribute holds them.
+	pub(crate) fn attribute_chunks_count(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: &BoundedVec<u8, T::KeyLimit>,
+	) -> Option<u32> {
+		let value_type =
+			AttributeValueTypeOf::<T, I>::get((collection, maybe_item, namespace, key));
+		if value_type != Some(AttributeValueType::ChunkManifest) {
+			return None
+		}
+		let (manifest, _) = Attribute::<T, I>::get((collection, maybe_item, namespace, key))?;
+		u32::decode(&mut &manifest[..])
+			.ok()
+			.map(|count| count.min(T::MaxAttributeChunks::get()))
 	}
 
 	pub(crate) fn do_clear_attribute(
@@ -225,8 +888,50 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		namespace: AttributeNamespace<T::AccountId>,
 		key: BoundedVec<u8, T::KeyLimit>,
 	) -> DispatchResult {
-		let (_, deposit) = Attribute::<T, I>::take((collection, maybe_item, &namespace, &key))
+		ensure!(!key.is_empty(), Error::<T, I>::EmptyKey);
+		// the chunks would be left behind otherwise
+		ensure!(
+			AttributeValueTypeOf::<T, I>::get((&collection, maybe_item, &namespace, &key)) !=
+				Some(AttributeValueType::ChunkManifest),
+			Error::<T, I>::LargeAttributeNotClearable
+		);
+		Self::ensure_attribute_key_unlocked(&collection, maybe_item, &namespace, &key)?;
+		Self::remove_attribute(maybe_check_owner, collection, maybe_item, namespace, key)
+	}
+
+	/// Clears the attribute regardless of the collection's, the item's or the key's lock state.
+	///
+	/// The deposit is returned to the account that paid it. Meant for the emergency removal of
+	/// malicious attributes by the governance.
+	pub(crate) fn do_force_clear_attribute(
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+	) -> DispatchResult {
+		ensure!(!key.is_empty(), Error::<T, I>::EmptyKey);
+		Self::remove_attribute(None, collection, maybe_item, namespace, key)
+	}
+
+	/// Removes the attribute, returning its deposit and updating the counters.
+	///
+	/// The permissions and the lock state are only checked when `maybe_check_owner` is provided,
+	/// the key lock is up to the caller.
+	fn remove_attribute(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+	) -> DispatchResult {
+		let (value, deposit) = Attribute::<T, I>::take((collection, maybe_item, &namespace, &key))
 			.ok_or(Error::<T, I>::AttributeNotFound)?;
+		AttributeValueTypeOf::<T, I>::remove((collection, maybe_item, &namespace, &key));
+		AttributeExpiryOf::<T, I>::remove((collection, maybe_item, &namespace, &key));
+		AttributeLastSetOf::<T, I>::remove((collection, maybe_item, &namespace, &key));
+		Self::decrease_item_attributes(&collection, maybe_item, 1);
+		Self::note_delegate_attributes(&collection, maybe_item, &namespace, 1, false);
+		Self::note_namespace_attributes(&collection, &namespace, 1, false);
 		let mut collection_details =
 			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 
@@ -259,7 +964,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					},
 					Some(item) => {
 						// NOTE: if the item was previously burned, the ItemConfigOf record
-						// might not exist. In that case, we allow to clear the attribute.
+						// might not exist. In that case, we allow to clear the attribute unless
+						// the collection's attributes are locked.
 						let maybe_is_locked = Self::get_item_config(&collection, &item)
 							.map_or(None, |c| {
 								Some(c.has_disabled_setting(ItemSetting::UnlockedAttributes))
@@ -274,7 +980,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 								);
 								ensure!(!is_locked, Error::<T, I>::LockedItemAttributes);
 							},
-							None => (),
+							None => {
+								let collection_config = Self::get_collection_config(&collection)?;
+								ensure!(
+									collection_config
+										.is_setting_enabled(CollectionSetting::UnlockedAttributes),
+									Error::<T, I>::CannotInferLockState
+								);
+							},
 						}
 					},
 				},
@@ -282,27 +995,696 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			};
 		}
 
-		collection_details.attributes.saturating_dec();
+		Self::decrease_collection_attributes(&mut collection_details, 1);
 
-		match deposit.account {
-			Some(deposit_account) => {
-				T::Currency::unreserve(&deposit_account, deposit.amount);
-			},
+		let maybe_depositor = match deposit.account {
+			Some(deposit_account) => Some(deposit_account),
 			None if namespace == AttributeNamespace::CollectionOwner => {
 				collection_details.owner_deposit.saturating_reduce(deposit.amount);
-				T::Currency::unreserve(&collection_details.owner, deposit.amount);
+				Some(collection_details.owner.clone())
 			},
-			_ => (),
+			_ => None,
+		};
+		if let Some(depositor) = maybe_depositor {
+			Self::return_attribute_deposit(&collection, &depositor, deposit.amount);
 		}
 
 		Collection::<T, I>::insert(collection, &collection_details);
-		Self::deposit_event(Event::AttributeCleared { collection, maybe_item, key, namespace });
+		Self::deposit_event(Event::AttributeCleared {
+			collection,
+			maybe_item,
+			key,
+			namespace,
+			value,
+			deposit: deposit.amount,
+		});
 
 		Ok(())
 	}
 
-	pub(crate) fn do_approve_item_attributes(
-		check_origin: T::AccountId,
+	pub(crate) fn do_set_attribute_with_expiry(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+		value: BoundedVec<u8, T::ValueLimit>,
+		expiry: <T as SystemConfig>::BlockNumber,
+		depositor: T::AccountId,
+	) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(expiry > now, Error::<T, I>::InvalidExpiry);
+
+		Self::do_set_attribute(
+			origin,
+			collection,
+			maybe_item,
+			namespace.clone(),
+			key.clone(),
+			value,
+			depositor,
+		)?;
+		AttributeExpiryOf::<T, I>::insert((&collection, maybe_item, &namespace, &key), expiry);
+		Self::deposit_event(Event::AttributeExpirySet {
+			collection,
+			maybe_item,
+			namespace,
+			key,
+			expiry,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_set_attribute_if(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+		expected: Option<BoundedVec<u8, T::ValueLimit>>,
+		value: BoundedVec<u8, T::ValueLimit>,
+		depositor: T::AccountId,
+	) -> DispatchResult {
+		// the expired attribute is considered absent
+		let current = Attribute::<T, I>::get((collection, maybe_item, &namespace, &key))
+			.filter(|_| !Self::is_attribute_expired(&collection, maybe_item, &namespace, &key))
+			.map(|(value, _)| value);
+		ensure!(current == expected, Error::<T, I>::PreconditionFailed);
+
+		Self::do_set_attribute(origin, collection, maybe_item, namespace, key, value, depositor)
+	}
+
+	pub(crate) fn do_move_attribute(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		from_namespace: AttributeNamespace<T::AccountId>,
+		to_namespace: AttributeNamespace<T::AccountId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+	) -> DispatchResult {
+		let (value, _) = Attribute::<T, I>::get((collection, Some(item), &from_namespace, &key))
+			.ok_or(Error::<T, I>::AttributeNotFound)?;
+		let value_type =
+			AttributeValueTypeOf::<T, I>::get((collection, Some(item), &from_namespace, &key))
+				.unwrap_or_default();
+		// the chunks are stored as separate attributes within the same namespace
+		ensure!(
+			value_type != AttributeValueType::ChunkManifest,
+			Error::<T, I>::LargeAttributeNotMovable
+		);
+
+		Self::do_clear_attribute(
+			Some(origin.clone()),
+			collection,
+			Some(item),
+			from_namespace,
+			key.clone(),
+		)?;
+		Self::do_set_typed_attribute(
+			origin.clone(),
+			collection,
+			Some(item),
+			to_namespace,
+			key,
+			value,
+			value_type,
+			origin,
+		)
+	}
+
+	pub(crate) fn do_reap_expired_attribute(
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_attribute_expired(&collection, maybe_item, &namespace, &key),
+			Error::<T, I>::AttributeNotExpired
+		);
+		// the attribute has no value anymore, so it gets removed as by the root, even if its key
+		// is locked
+		Self::remove_attribute(None, collection, maybe_item, namespace, key)
+	}
+
+	/// Returns `true` if the attribute has an expiry and it has already been reached.
+	pub(crate) fn is_attribute_expired(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: &[u8],
+	) -> bool {
+		let Ok(key) = BoundedSlice::<_, T::KeyLimit>::try_from(key) else { return false };
+		Self::has_expired(AttributeExpiryOf::<T, I>::get((collection, maybe_item, namespace, key)))
+	}
+
+	/// Returns `true` if the `maybe_expiry` block has already been reached.
+	pub(crate) fn has_expired(maybe_expiry: Option<<T as SystemConfig>::BlockNumber>) -> bool {
+		maybe_expiry.map_or(false, |expiry| expiry <= frame_system::Pallet::<T>::block_number())
+	}
+
+	pub(crate) fn do_clear_resolved_attribute(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: BoundedVec<u8, T::KeyLimit>,
+	) -> DispatchResult {
+		let namespace = T::NamespacePrecedence::namespace_precedence_opt(&collection, &item, &key)
+			.ok_or(Error::<T, I>::AttributeNotFound)?;
+		Self::do_clear_attribute(maybe_check_owner, collection, Some(item), namespace, key)
+	}
+
+	pub(crate) fn do_clear_item_namespace(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		namespace: AttributeNamespace<T::AccountId>,
+		witness: ClearItemNamespaceWitness,
+	) -> DispatchResult {
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(
+				Self::is_valid_namespace(
+					check_owner,
+					&namespace,
+					&collection,
+					&collection_details.owner,
+					&Some(item),
+				)?,
+				Error::<T, I>::NoPermission
+			);
+
+			// can't clear `CollectionOwner` type attributes if the item is locked
+			if namespace == AttributeNamespace::CollectionOwner {
+				let is_locked = Self::get_item_config(&collection, &item)
+					.map(|c| c.has_disabled_setting(ItemSetting::UnlockedAttributes))?;
+				ensure!(!is_locked, Error::<T, I>::LockedItemAttributes);
+			}
+		}
+		// the locked keys can't be cleared even by the `ForceOrigin`
+		if namespace == AttributeNamespace::CollectionOwner {
+			ensure!(
+				LockedAttributeKeysOf::<T, I>::iter_key_prefix((&collection, Some(item)))
+					.next()
+					.is_none(),
+				Error::<T, I>::AttributeLocked
+			);
+		}
+		// the witness is checked before any attribute gets removed
+		let keys = Attribute::<T, I>::iter_key_prefix((&collection, Some(item), &namespace))
+			.take(witness.attributes.saturating_add(1) as usize)
+			.count();
+		ensure!(keys <= witness.attributes as usize, Error::<T, I>::BadWitness);
+
+		let mut attributes: u32 = 0;
+		for (_, (_, deposit)) in
+			Attribute::<T, I>::drain_prefix((&collection, Some(item), &namespace))
+		{
+			attributes.saturating_inc();
+			match deposit.account {
+				Some(deposit_account) => {
+					Self::return_attribute_deposit(&collection, &deposit_account, deposit.amount);
+				},
+				None if namespace == AttributeNamespace::CollectionOwner => {
+					collection_details.owner_deposit.saturating_reduce(deposit.amount);
+					Self::return_attribute_deposit(
+						&collection,
+						&collection_details.owner,
+						deposit.amount,
+					);
+				},
+				_ => (),
+			}
+		}
+		let _ = AttributeValueTypeOf::<T, I>::clear_prefix(
+			(&collection, Some(item), &namespace),
+			attributes,
+			None,
+		);
+		let _ = AttributeExpiryOf::<T, I>::clear_prefix(
+			(&collection, Some(item), &namespace),
+			attributes,
+			None,
+		);
+		let _ = AttributeLastSetOf::<T, I>::clear_prefix(
+			(&collection, Some(item), &namespace),
+			attributes,
+			None,
+		);
+		Self::decrease_item_attributes(&collection, Some(item), attributes);
+		Self::note_delegate_attributes(&collection, Some(item), &namespace, attributes, false);
+		Self::note_namespace_attributes(&collection, &namespace, attributes, false);
+
+		Self::decrease_collection_attributes(&mut collection_details, attributes);
+		Collection::<T, I>::insert(collection, &collection_details);
+		Self::deposit_event(Event::ItemNamespaceCleared {
+			collection,
+			item,
+			namespace,
+			attributes,
+		});
+		Ok(())
+	}
+
+	/// Clears up to `witness.attributes` of the `collection`'s attributes across all the items
+	/// and namespaces, returning the deposits to the accounts that paid them.
+	///
+	/// Returns the amount of the attributes left to clear.
+	pub(crate) fn do_clear_all_collection_attributes(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		witness: AttributeCleanupWitness,
+	) -> Result<u32, DispatchError> {
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &collection_details.owner, Error::<T, I>::NoPermission);
+			// the locks and the other accounts' attributes of the live items are out of the
+			// owner's reach
+			ensure!(collection_details.items.is_zero(), Error::<T, I>::CollectionNotEmpty);
+		}
+
+		let batch: Vec<_> = Attribute::<T, I>::iter_prefix((&collection,))
+			.take(witness.attributes as usize)
+			.collect();
+		let attributes = batch.len() as u32;
+		for ((maybe_item, namespace, key), (_, deposit)) in batch {
+			Attribute::<T, I>::remove((&collection, maybe_item, &namespace, &key));
+			AttributeValueTypeOf::<T, I>::remove((&collection, maybe_item, &namespace, &key));
+			AttributeExpiryOf::<T, I>::remove((&collection, maybe_item, &namespace, &key));
+			AttributeLastSetOf::<T, I>::remove((&collection, maybe_item, &namespace, &key));
+			Self::decrease_item_attributes(&collection, maybe_item, 1);
+			Self::note_delegate_attributes(&collection, maybe_item, &namespace, 1, false);
+			Self::note_namespace_attributes(&collection, &namespace, 1, false);
+
+			match deposit.account {
+				Some(deposit_account) => {
+					Self::return_attribute_deposit(&collection, &deposit_account, deposit.amount);
+				},
+				None if namespace == AttributeNamespace::CollectionOwner => {
+					collection_details.owner_deposit.saturating_reduce(deposit.amount);
+					Self::return_attribute_deposit(
+						&collection,
+						&collection_details.owner,
+						deposit.amount,
+					);
+				},
+				_ => (),
+			}
+		}
+
+		Self::decrease_collection_attributes(&mut collection_details, attributes);
+		let remaining = collection_details.attributes;
+		Collection::<T, I>::insert(collection, &collection_details);
+		Self::deposit_event(Event::CollectionAttributesCleared {
+			collection,
+			attributes,
+			remaining,
+		});
+		Ok(remaining)
+	}
+
+	pub(crate) fn do_lock_attribute(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Attributes),
+			Error::<T, I>::MethodDisabled
+		);
+		let collection_owner =
+			Self::collection_owner(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		ensure!(origin == collection_owner, Error::<T, I>::NoPermission);
+		ensure!(
+			Attribute::<T, I>::contains_key((
+				&collection,
+				maybe_item,
+				AttributeNamespace::CollectionOwner,
+				&key
+			)),
+			Error::<T, I>::AttributeNotFound
+		);
+
+		LockedAttributeKeysOf::<T, I>::insert((&collection, maybe_item, &key), ());
+		Self::deposit_event(Event::AttributeKeyLocked { collection, maybe_item, key });
+		Ok(())
+	}
+
+	/// Ensures the `key` attribute within the `namespace` wasn't locked by the collection's owner.
+	fn ensure_attribute_key_unlocked(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: &BoundedVec<u8, T::KeyLimit>,
+	) -> DispatchResult {
+		if *namespace == AttributeNamespace::CollectionOwner {
+			ensure!(
+				!LockedAttributeKeysOf::<T, I>::contains_key((collection, maybe_item, key)),
+				Error::<T, I>::AttributeLocked
+			);
+		}
+		Ok(())
+	}
+
+	/// Ensures the `key` doesn't start with any of `T::ReservedKeyPrefixes` unless it's written
+	/// within the `CollectionOwner` or the `Pallet` namespace.
+	fn ensure_attribute_key_not_reserved(
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: &BoundedVec<u8, T::KeyLimit>,
+	) -> DispatchResult {
+		match namespace {
+			AttributeNamespace::CollectionOwner | AttributeNamespace::Pallet => Ok(()),
+			_ => {
+				ensure!(
+					!T::ReservedKeyPrefixes::get().iter().any(|prefix| key.starts_with(prefix)),
+					Error::<T, I>::ReservedKey
+				);
+				Ok(())
+			},
+		}
+	}
+
+	/// Counts the `writes` into the `collection` made within the current block, failing once the
+	/// `T::MaxAttributeWritesPerBlock` is exceeded.
+	fn note_attribute_writes(collection: &T::CollectionId, writes: u32) -> DispatchResult {
+		let Some(max_writes) = T::MaxAttributeWritesPerBlock::get() else { return Ok(()) };
+		AttributeWritesInBlock::<T, I>::try_mutate(collection, |count| {
+			*count = count.saturating_add(writes);
+			ensure!(*count <= max_writes, Error::<T, I>::RateLimited);
+			Ok(())
+		})
+	}
+
+	pub(crate) fn do_set_attribute_schema(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		key: BoundedVec<u8, T::KeyLimit>,
+		constraint: Option<ValueConstraintOf<T, I>>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Attributes),
+			Error::<T, I>::MethodDisabled
+		);
+		ensure!(!key.is_empty(), Error::<T, I>::EmptyKey);
+		let collection_owner =
+			Self::collection_owner(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		ensure!(origin == collection_owner, Error::<T, I>::NoPermission);
+
+		let schema_exists = AttributeSchemaOf::<T, I>::contains_key(&collection, &key);
+		match &constraint {
+			Some(constraint) => {
+				if !schema_exists {
+					AttributeSchemasCountOf::<T, I>::try_mutate(&collection, |count| {
+						ensure!(
+							*count < T::MaxAttributeSchemas::get(),
+							Error::<T, I>::MaxAttributeSchemasReached
+						);
+						count.saturating_inc();
+						Ok::<(), DispatchError>(())
+					})?;
+				}
+				AttributeSchemaOf::<T, I>::insert(&collection, &key, constraint);
+			},
+			None => {
+				if schema_exists {
+					AttributeSchemasCountOf::<T, I>::mutate(&collection, |count| {
+						count.saturating_dec()
+					});
+				}
+				AttributeSchemaOf::<T, I>::remove(&collection, &key);
+			},
+		}
+
+		Self::deposit_event(Event::AttributeSchemaSet { collection, key, constraint });
+		Ok(())
+	}
+
+	/// Ensures the `value` of the `CollectionOwner` namespace attribute satisfies the schema
+	/// defined for its `key`, if any.
+	fn ensure_schema_satisfied(
+		collection: &T::CollectionId,
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: &BoundedVec<u8, T::KeyLimit>,
+		value: &BoundedVec<u8, T::ValueLimit>,
+	) -> DispatchResult {
+		if *namespace == AttributeNamespace::CollectionOwner {
+			if let Some(constraint) = AttributeSchemaOf::<T, I>::get(collection, key) {
+				ensure!(constraint.is_satisfied_by(value), Error::<T, I>::SchemaViolation);
+			}
+		}
+		Ok(())
+	}
+
+	pub(crate) fn do_transfer_item_attributes(
+		from: T::AccountId,
+		to: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		witness: TransferItemAttributesWitness,
+	) -> DispatchResult {
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(to == details.owner, Error::<T, I>::NoPermission);
+
+		let namespace = AttributeNamespace::ItemOwner;
+		// the witness is checked before any value gets loaded
+		let keys = Attribute::<T, I>::iter_key_prefix((&collection, Some(item), &namespace))
+			.take(witness.attributes.saturating_add(1) as usize)
+			.count();
+		ensure!(keys <= witness.attributes as usize, Error::<T, I>::BadWitness);
+
+		let transferred =
+			Self::move_item_attribute_deposits(collection, item, &from, &to, |_, error| {
+				Err(error)
+			})?;
+
+		Self::deposit_event(Event::ItemAttributesTransferred {
+			collection,
+			item,
+			from,
+			to,
+			attributes: transferred,
+		});
+		Ok(())
+	}
+
+	/// Moves the deposits of the item's `ItemOwner` attributes paid by `from` to `to`.
+	///
+	/// Called on item transfer when `T::AutoMoveItemAttributes` is enabled. If `to` can't afford
+	/// the deposit of an attribute, the deposit stays reserved from `from` and
+	/// `ItemAttributeDepositNotMoved` is emitted.
+	///
+	/// NOTE: `to` takes over up to `T::MaxAttributesPerItem` deposits it didn't agree to, e.g.
+	/// the buyer of an item takes over the deposits of the seller's attributes on top of the
+	/// price.
+	pub fn on_item_transferred_attributes(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+	) {
+		let transferred =
+			Self::move_item_attribute_deposits(collection, item, from, to, |key, _| {
+				Self::deposit_event(Event::ItemAttributeDepositNotMoved {
+					collection,
+					item,
+					key,
+					from: from.clone(),
+					to: to.clone(),
+				});
+				Ok(())
+			})
+			.unwrap_or_default();
+
+		if transferred > 0 {
+			Self::deposit_event(Event::ItemAttributesTransferred {
+				collection,
+				item,
+				from: from.clone(),
+				to: to.clone(),
+				attributes: transferred,
+			});
+		}
+	}
+
+	/// The weight of moving the attribute deposits on an item transfer, if
+	/// `T::AutoMoveItemAttributes` is enabled.
+	pub(crate) fn moved_item_attributes_weight() -> Weight {
+		if T::AutoMoveItemAttributes::get() {
+			T::WeightInfo::transfer_item_attributes(T::MaxAttributesPerItem::get())
+		} else {
+			Weight::zero()
+		}
+	}
+
+	/// Moves the deposits of the item's `ItemOwner` attributes paid by `from` to `to` and
+	/// returns the number of the moved deposits.
+	///
+	/// When `to` can't afford the deposit of the `key` attribute, the deposit stays with `from`
+	/// unless `on_failed` returns an error, which aborts the whole move.
+	fn move_item_attribute_deposits(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		mut on_failed: impl FnMut(BoundedVec<u8, T::KeyLimit>, DispatchError) -> DispatchResult,
+	) -> Result<u32, DispatchError> {
+		let namespace = AttributeNamespace::ItemOwner;
+		let attributes: Vec<_> =
+			Attribute::<T, I>::iter_prefix((&collection, Some(item), &namespace)).collect();
+
+		let mut moved: u32 = 0;
+		for (key, (value, deposit)) in attributes {
+			if deposit.account.as_ref() != Some(from) {
+				continue
+			}
+			if let Err(error) = T::AttributeDepositHandler::reserve(&collection, to, deposit.amount)
+			{
+				on_failed(key, error)?;
+				continue
+			}
+			Self::return_attribute_deposit(&collection, from, deposit.amount);
+			Attribute::<T, I>::insert(
+				(&collection, Some(item), &namespace, &key),
+				(value, AttributeDeposit { account: Some(to.clone()), amount: deposit.amount }),
+			);
+			moved.saturating_inc();
+		}
+		Ok(moved)
+	}
+
+	pub(crate) fn do_clear_orphaned_attributes(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		witness: ClearOrphanedAttributesWitness,
+	) -> DispatchResult {
+		ensure!(!Item::<T, I>::contains_key(&collection, &item), Error::<T, I>::ItemNotBurned);
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let collection_config = Self::get_collection_config(&collection)?;
+		let attributes_locked =
+			collection_config.has_disabled_setting(CollectionSetting::UnlockedAttributes);
+
+		let orphaned: Vec<_> = Attribute::<T, I>::iter_prefix((&collection, Some(item)))
+			.take(witness.attributes.saturating_add(1) as usize)
+			.collect();
+		ensure!(orphaned.len() <= witness.attributes as usize, Error::<T, I>::BadWitness);
+
+		let mut attributes: u32 = 0;
+		for ((namespace, key), (_, deposit)) in orphaned {
+			let is_collection_owner_namespace = namespace == AttributeNamespace::CollectionOwner;
+			if is_collection_owner_namespace &&
+				(attributes_locked ||
+					LockedAttributeKeysOf::<T, I>::contains_key((
+						&collection,
+						Some(item),
+						&key,
+					))) {
+				continue
+			}
+
+			Attribute::<T, I>::remove((&collection, Some(item), &namespace, &key));
+			AttributeValueTypeOf::<T, I>::remove((&collection, Some(item), &namespace, &key));
+			AttributeExpiryOf::<T, I>::remove((&collection, Some(item), &namespace, &key));
+			AttributeLastSetOf::<T, I>::remove((&collection, Some(item), &namespace, &key));
+			Self::note_delegate_attributes(&collection, Some(item), &namespace, 1, false);
+			Self::note_namespace_attributes(&collection, &namespace, 1, false);
+			attributes.saturating_inc();
+
+			match deposit.account {
+				Some(deposit_account) => {
+					Self::return_attribute_deposit(&collection, &deposit_account, deposit.amount);
+				},
+				None if is_collection_owner_namespace => {
+					collection_details.owner_deposit.saturating_reduce(deposit.amount);
+					Self::return_attribute_deposit(
+						&collection,
+						&collection_details.owner,
+						deposit.amount,
+					);
+				},
+				_ => (),
+			}
+		}
+
+		Self::decrease_collection_attributes(&mut collection_details, attributes);
+		Self::decrease_item_attributes(&collection, Some(item), attributes);
+		Collection::<T, I>::insert(collection, &collection_details);
+		Self::deposit_event(Event::OrphanedAttributesCleared { collection, item, attributes });
+		Ok(())
+	}
+
+	pub(crate) fn do_recalculate_attribute_deposit(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+	) -> DispatchResult {
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let (value, deposit) = Attribute::<T, I>::get((collection, maybe_item, &namespace, &key))
+			.ok_or(Error::<T, I>::AttributeNotFound)?;
+
+		// NOTE: in the CollectionOwner namespace if the depositor is `None` that means the deposit
+		// was paid by the collection's owner.
+		let is_collection_owner_deposit =
+			namespace == AttributeNamespace::CollectionOwner && deposit.account.is_none();
+		let depositor = match &deposit.account {
+			Some(account) => Some(account.clone()),
+			None if is_collection_owner_deposit => Some(collection_details.owner.clone()),
+			None => None,
+		};
+		ensure!(
+			depositor.as_ref() == Some(&origin) || collection_details.owner == origin,
+			Error::<T, I>::NoPermission
+		);
+
+		let collection_config = Self::get_collection_config(&collection)?;
+		let mut new_deposit = Zero::zero();
+		// disabled DepositRequired setting only affects the CollectionOwner namespace
+		if collection_config.is_setting_enabled(CollectionSetting::DepositRequired) ||
+			namespace != AttributeNamespace::CollectionOwner
+		{
+			new_deposit = T::DepositPerByte::get()
+				.saturating_mul(((key.len() + value.len()) as u32).into())
+				.saturating_add(T::AttributeDepositBase::get());
+		}
+
+		// only the over-reserved deposits are refunded
+		if new_deposit >= deposit.amount {
+			return Ok(())
+		}
+		let refund = deposit.amount - new_deposit;
+		if let Some(depositor) = &depositor {
+			Self::return_attribute_deposit(&collection, depositor, refund);
+		}
+		if is_collection_owner_deposit {
+			collection_details.owner_deposit.saturating_reduce(refund);
+			Collection::<T, I>::insert(collection, &collection_details);
+		}
+
+		let old_deposit = deposit.amount;
+		Attribute::<T, I>::insert(
+			(&collection, maybe_item, &namespace, &key),
+			(&value, AttributeDeposit { account: deposit.account, amount: new_deposit }),
+		);
+		Self::deposit_event(Event::AttributeDepositUpdated {
+			collection,
+			maybe_item,
+			namespace,
+			key,
+			old_deposit,
+			new_deposit,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_approve_item_attributes(
+		check_origin: T::AccountId,
 		collection: T::CollectionId,
 		item: T::ItemId,
 		delegate: T::AccountId,
@@ -321,17 +1703,122 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				.map_err(|_| Error::<T, I>::ReachedApprovalLimit)?;
 
 			Self::deposit_event(Event::ItemAttributesApprovalAdded { collection, item, delegate });
+			Self::note_item_attributes_approvals(collection, item, approvals.len() as u32);
+			Ok(())
+		})
+	}
+
+	/// Same as `do_approve_item_attributes()`, but approves all the `delegates` at once.
+	///
+	/// Nothing gets approved if the `delegates` don't fit into the approvals limit altogether.
+	pub(crate) fn do_approve_item_attributes_batch(
+		check_origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		delegates: BoundedVec<T::AccountId, T::ItemAttributesApprovalsLimit>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Attributes),
+			Error::<T, I>::MethodDisabled
+		);
+
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(check_origin == details.owner, Error::<T, I>::NoPermission);
+
+		let approvals =
+			ItemAttributesApprovalsOf::<T, I>::try_mutate(collection, item, |approvals| {
+				for delegate in delegates.iter() {
+					approvals
+						.try_insert(delegate.clone())
+						.map_err(|_| Error::<T, I>::ReachedApprovalLimit)?;
+				}
+				Ok::<_, DispatchError>(approvals.len() as u32)
+			})?;
+
+		for delegate in delegates {
+			Self::deposit_event(Event::ItemAttributesApprovalAdded { collection, item, delegate });
+		}
+		Self::note_item_attributes_approvals(collection, item, approvals);
+		Ok(())
+	}
+
+	pub(crate) fn do_add_collection_attribute_editor(
+		check_origin: T::AccountId,
+		collection: T::CollectionId,
+		editor: T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Attributes),
+			Error::<T, I>::MethodDisabled
+		);
+
+		let owner = Self::collection_owner(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		ensure!(check_origin == owner, Error::<T, I>::NoPermission);
+
+		CollectionAttributeEditorsOf::<T, I>::try_mutate(collection, |editors| {
+			editors
+				.try_insert(editor.clone())
+				.map_err(|_| Error::<T, I>::ReachedCollectionAttributeEditorsLimit)?;
+
+			Self::deposit_event(Event::CollectionAttributeEditorAdded { collection, editor });
+			Ok(())
+		})
+	}
+
+	pub(crate) fn do_remove_collection_attribute_editor(
+		check_origin: T::AccountId,
+		collection: T::CollectionId,
+		editor: T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Attributes),
+			Error::<T, I>::MethodDisabled
+		);
+
+		let owner = Self::collection_owner(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		ensure!(check_origin == owner, Error::<T, I>::NoPermission);
+
+		CollectionAttributeEditorsOf::<T, I>::try_mutate_exists(collection, |maybe_editors| {
+			let editors =
+				maybe_editors.as_mut().ok_or(Error::<T, I>::NotCollectionAttributeEditor)?;
+			ensure!(editors.remove(&editor), Error::<T, I>::NotCollectionAttributeEditor);
+			if editors.is_empty() {
+				*maybe_editors = None;
+			}
+
+			Self::deposit_event(Event::CollectionAttributeEditorRemoved { collection, editor });
 			Ok(())
 		})
 	}
 
+	/// Warns the item's owner once the number of the item's attributes `approvals` gets within
+	/// the `T::ItemAttributesApprovalsNearLimitThreshold` of the `T::ItemAttributesApprovalsLimit`.
+	fn note_item_attributes_approvals(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		approvals: u32,
+	) {
+		let remaining = T::ItemAttributesApprovalsLimit::get().saturating_sub(approvals);
+		if remaining <= T::ItemAttributesApprovalsNearLimitThreshold::get() {
+			Self::deposit_event(Event::ItemAttributesApprovalNearLimit {
+				collection,
+				item,
+				remaining,
+			});
+		}
+	}
+
+	/// Removes the `delegate`'s approval along with all the attributes it has set and returns the
+	/// number of the removed attributes.
+	///
+	/// The number of the `delegate`'s attributes is tracked within the storage, so no witness is
+	/// required.
 	pub(crate) fn do_cancel_item_attributes_approval(
 		check_origin: T::AccountId,
 		collection: T::CollectionId,
 		item: T::ItemId,
 		delegate: T::AccountId,
-		witness: CancelAttributesApprovalWitness,
-	) -> DispatchResult {
+	) -> Result<u32, DispatchError> {
 		ensure!(
 			Self::is_pallet_feature_enabled(PalletFeature::Attributes),
 			Error::<T, I>::MethodDisabled
@@ -343,6 +1830,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ItemAttributesApprovalsOf::<T, I>::try_mutate(collection, item, |approvals| {
 			approvals.remove(&delegate);
 
+			ItemAttributesDelegateCount::<T, I>::remove((&collection, &item, &delegate));
+
 			let mut attributes: u32 = 0;
 			let mut deposited: DepositBalanceOf<T, I> = Zero::zero();
 			for (_, (_, deposit)) in Attribute::<T, I>::drain_prefix((
@@ -351,23 +1840,135 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				AttributeNamespace::Account(delegate.clone()),
 			)) {
 				attributes.saturating_inc();
-				deposited = deposited.saturating_add(deposit.amount);
+				match deposit.account {
+					// the deposit was sponsored by the item's owner
+					Some(sponsor) if sponsor != delegate => {
+						Self::return_attribute_deposit(&collection, &sponsor, deposit.amount);
+					},
+					_ => deposited = deposited.saturating_add(deposit.amount),
+				}
+			}
+			let _ = AttributeValueTypeOf::<T, I>::clear_prefix(
+				(&collection, Some(item), AttributeNamespace::Account(delegate.clone())),
+				attributes,
+				None,
+			);
+			let _ = AttributeExpiryOf::<T, I>::clear_prefix(
+				(&collection, Some(item), AttributeNamespace::Account(delegate.clone())),
+				attributes,
+				None,
+			);
+			let _ = AttributeLastSetOf::<T, I>::clear_prefix(
+				(&collection, Some(item), AttributeNamespace::Account(delegate.clone())),
+				attributes,
+				None,
+			);
+			Self::decrease_item_attributes(&collection, Some(item), attributes);
+			Self::note_namespace_attributes(
+				&collection,
+				&AttributeNamespace::Account(delegate.clone()),
+				attributes,
+				false,
+			);
+			if !attributes.is_zero() {
+				Collection::<T, I>::try_mutate(&collection, |maybe_details| {
+					let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
+					Self::decrease_collection_attributes(details, attributes);
+					Ok::<(), DispatchError>(())
+				})?;
 			}
-			ensure!(attributes <= witness.account_attributes, Error::<T, I>::BadWitness);
 
+			let mut refunded = deposited;
 			if !deposited.is_zero() {
-				T::Currency::unreserve(&delegate, deposited);
+				refunded = Self::return_attribute_deposit(&collection, &delegate, deposited);
 			}
 
 			Self::deposit_event(Event::ItemAttributesApprovalRemoved {
 				collection,
 				item,
 				delegate,
+				attributes_removed: attributes,
+				deposit_refunded: refunded,
 			});
-			Ok(())
+			Ok(attributes)
 		})
 	}
 
+	/// Checks whether the `origin` is allowed to set attributes within the `namespace` of the
+	/// `collection` or of its item.
+	pub fn can_set_attribute(
+		origin: &T::AccountId,
+		namespace: &AttributeNamespace<T::AccountId>,
+		collection: &T::CollectionId,
+		maybe_item: &Option<T::ItemId>,
+	) -> Result<bool, DispatchError> {
+		let collection_owner =
+			Self::collection_owner(*collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		Self::is_valid_namespace(origin, namespace, collection, &collection_owner, maybe_item)
+	}
+
+	/// Simulate setting an attribute by the `origin` without persisting any change, and return
+	/// the effect it would have on the deposits.
+	///
+	/// The same checks as in `set_attribute` are performed, so the error it would fail with is
+	/// returned.
+	pub fn simulate_set_attribute(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		key: Vec<u8>,
+		value: Vec<u8>,
+	) -> Result<SetAttributeEffect<T::AccountId, DepositBalanceOf<T, I>>, DispatchError> {
+		let key = Self::construct_attribute_key(key)?;
+		let value = Self::construct_attribute_value(value)?;
+		let collection_owner =
+			Self::collection_owner(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let old_deposit = Attribute::<T, I>::get((&collection, maybe_item, &namespace, &key))
+			.map(|(_, deposit)| deposit);
+
+		// all the changes are rolled back, only the resulting deposit record is kept
+		let new_deposit = with_transaction(|| {
+			let result = Self::do_set_attribute(
+				origin.clone(),
+				collection,
+				maybe_item,
+				namespace.clone(),
+				key.clone(),
+				value,
+				origin,
+			)
+			.and_then(|()| {
+				Attribute::<T, I>::get((&collection, maybe_item, &namespace, &key))
+					.map(|(_, deposit)| deposit)
+					.ok_or(Error::<T, I>::AttributeNotFound.into())
+			});
+			TransactionOutcome::Rollback(result)
+		})?;
+
+		// NOTE: in the CollectionOwner namespace if the depositor is `None` that means the deposit
+		// was paid by the collection's owner.
+		let is_collection_owner_namespace = namespace == AttributeNamespace::CollectionOwner;
+		let deposit_account = new_deposit.account.unwrap_or_else(|| collection_owner.clone());
+		let old_depositor = old_deposit.as_ref().and_then(|deposit| match &deposit.account {
+			None if is_collection_owner_namespace => Some(collection_owner.clone()),
+			account => account.clone(),
+		});
+
+		let will_create = old_deposit.is_none();
+		let deposit_delta = match old_deposit {
+			Some(old_deposit) if old_depositor.as_ref() == Some(&deposit_account) =>
+				if old_deposit.amount > new_deposit.amount {
+					DepositDelta::Unreserve(old_deposit.amount - new_deposit.amount)
+				} else {
+					DepositDelta::Reserve(new_deposit.amount - old_deposit.amount)
+				},
+			_ => DepositDelta::Reserve(new_deposit.amount),
+		};
+
+		Ok(SetAttributeEffect { deposit_delta, will_create, deposit_account })
+	}
+
 	fn is_valid_namespace(
 		origin: &T::AccountId,
 		namespace: &AttributeNamespace<T::AccountId>,
@@ -384,16 +1985,202 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
 					result = origin == &item_details.owner
 				},
+			// NOTE: the collection's attribute editors reuse their `Account` namespace, it is just
+			// not restricted to the items they were approved for
 			AttributeNamespace::Account(account_id) =>
 				if let Some(item) = maybe_item {
 					let approvals = ItemAttributesApprovalsOf::<T, I>::get(&collection, &item);
-					result = account_id == origin && approvals.contains(&origin)
+					let editors = CollectionAttributeEditorsOf::<T, I>::get(&collection);
+					result = account_id == origin &&
+						(approvals.contains(&origin) || editors.contains(&origin))
 				},
 			_ => (),
 		};
 		Ok(result)
 	}
 
+	/// Stores the `value_type` of the attribute, `AttributeValueType::Raw` is stored implicitly.
+	fn set_attribute_value_type(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: &BoundedVec<u8, T::KeyLimit>,
+		value_type: AttributeValueType,
+	) {
+		let storage_key = (collection, maybe_item, namespace, key);
+		match value_type {
+			AttributeValueType::Raw => AttributeValueTypeOf::<T, I>::remove(storage_key),
+			_ => AttributeValueTypeOf::<T, I>::insert(storage_key, value_type),
+		}
+	}
+
+	/// Records the current block as the one the attribute was last set at.
+	fn note_attribute_last_set(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: &BoundedVec<u8, T::KeyLimit>,
+	) {
+		AttributeLastSetOf::<T, I>::insert(
+			(collection, maybe_item, namespace, key),
+			frame_system::Pallet::<T>::block_number(),
+		);
+	}
+
+	/// Decreases the number of the collection's attributes.
+	///
+	/// The counter never goes below zero, even if it got out of sync with the stored attributes.
+	fn decrease_collection_attributes(
+		collection_details: &mut CollectionDetailsFor<T, I>,
+		attributes: u32,
+	) {
+		debug_assert!(
+			collection_details.attributes >= attributes,
+			"the number of the collection's attributes is out of sync"
+		);
+		collection_details.attributes.saturating_reduce(attributes);
+	}
+
+	/// Increases the number of the item's attributes by one.
+	///
+	/// Fails when the item already holds `T::MaxAttributesPerItem` attributes. Does nothing for
+	/// the collection's attributes.
+	fn increase_item_attributes(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+	) -> DispatchResult {
+		if let Some(item) = maybe_item {
+			ItemAttributeCountOf::<T, I>::try_mutate(collection, item, |count| {
+				ensure!(
+					*count < T::MaxAttributesPerItem::get(),
+					Error::<T, I>::MaxAttributesReached
+				);
+				count.saturating_inc();
+				Ok(())
+			})
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Decreases the number of the item's attributes.
+	///
+	/// Does nothing for the collection's attributes.
+	fn decrease_item_attributes(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		attributes: u32,
+	) {
+		if let Some(item) = maybe_item {
+			ItemAttributeCountOf::<T, I>::mutate_exists(collection, item, |maybe_count| {
+				if let Some(count) = maybe_count {
+					count.saturating_reduce(attributes);
+					if count.is_zero() {
+						*maybe_count = None;
+					}
+				}
+			});
+		}
+	}
+
+	/// Emits `AttributeDepositChanged` if the deposit the `depositor` holds for the attribute has
+	/// changed from the `old_deposit` to the `deposit`.
+	fn note_attribute_deposit_change(
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: &BoundedVec<u8, T::KeyLimit>,
+		depositor: T::AccountId,
+		old_deposit: DepositBalanceOf<T, I>,
+		deposit: DepositBalanceOf<T, I>,
+	) {
+		if deposit == old_deposit {
+			return
+		}
+		let reserved = deposit > old_deposit;
+		let delta = if reserved { deposit - old_deposit } else { old_deposit - deposit };
+		Self::deposit_event(Event::AttributeDepositChanged {
+			collection,
+			maybe_item,
+			namespace: namespace.clone(),
+			key: key.clone(),
+			depositor,
+			delta,
+			reserved,
+			deposit,
+		});
+	}
+
+	/// Updates the number of attributes set by a delegated account within its namespace.
+	///
+	/// Does nothing for the rest of namespaces and for the collection's attributes.
+	fn note_delegate_attributes(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+		attributes: u32,
+		added: bool,
+	) {
+		if let (Some(item), AttributeNamespace::Account(delegate)) = (maybe_item, namespace) {
+			ItemAttributesDelegateCount::<T, I>::mutate((collection, item, delegate), |count| {
+				*count = match (count.take(), added) {
+					(Some(count), true) => Some(count.saturating_add(attributes)),
+					(Some(count), false) =>
+						Some(count.saturating_sub(attributes)).filter(|count| !count.is_zero()),
+					// the attributes are added before being stored, so the ones counted are
+					// the attributes set before the counter was introduced
+					(None, true) => Some(
+						Self::count_delegate_attributes(collection, item, delegate)
+							.saturating_add(attributes),
+					),
+					// the attributes are removed before the counter is updated, so it remains
+					// to be counted on the next access
+					(None, false) => None,
+				};
+			});
+		}
+	}
+
+	/// Counts the attributes within the `delegate`'s namespace of an item, for the delegates whose
+	/// attributes haven't been counted yet.
+	pub(crate) fn count_delegate_attributes(
+		collection: &T::CollectionId,
+		item: T::ItemId,
+		delegate: &T::AccountId,
+	) -> u32 {
+		Attribute::<T, I>::iter_key_prefix((
+			collection,
+			Some(item),
+			AttributeNamespace::Account(delegate.clone()),
+		))
+		.count() as u32
+	}
+
+	/// Updates the number of the collection's attributes within the kind of the `namespace`.
+	fn note_namespace_attributes(
+		collection: &T::CollectionId,
+		namespace: &AttributeNamespace<T::AccountId>,
+		attributes: u32,
+		added: bool,
+	) {
+		let kind = NamespaceDiscriminant::from(namespace);
+		CollectionNamespaceAttributeCountOf::<T, I>::mutate_exists(
+			collection,
+			kind,
+			|maybe_count| {
+				let count = maybe_count.get_or_insert(0);
+				if added {
+					count.saturating_accrue(attributes);
+				} else {
+					count.saturating_reduce(attributes);
+				}
+				if count.is_zero() {
+					*maybe_count = None;
+				}
+			},
+		);
+	}
+
 	/// A helper method to construct attribute's key.
 	pub fn construct_attribute_key(
 		key: Vec<u8>,
@@ -407,4 +2194,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> Result<BoundedVec<u8, T::ValueLimit>, DispatchError> {
 		Ok(BoundedVec::try_from(value).map_err(|_| Error::<T, I>::IncorrectData)?)
 	}
+
+	/// The maximum length of an attribute's key.
+	pub fn attribute_key_limit() -> u32 {
+		T::KeyLimit::get()
+	}
+
+	/// The maximum length of an attribute's value.
+	pub fn attribute_value_limit() -> u32 {
+		T::ValueLimit::get()
+	}
 }
@@ -95,10 +95,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			for (_, (_, deposit)) in Attribute::<T, I>::drain_prefix((&collection,)) {
 				if !deposit.amount.is_zero() {
 					if let Some(account) = deposit.account {
-						T::Currency::unreserve(&account, deposit.amount);
+						Self::return_attribute_deposit(&collection, &account, deposit.amount);
 					}
 				}
 			}
+			let _ = AttributeValueTypeOf::<T, I>::clear_prefix(
+				(&collection,),
+				witness.attributes,
+				None,
+			);
+			let _ =
+				AttributeExpiryOf::<T, I>::clear_prefix((&collection,), witness.attributes, None);
+			let _ =
+				AttributeLastSetOf::<T, I>::clear_prefix((&collection,), witness.attributes, None);
 
 			CollectionAccount::<T, I>::remove(&collection_details.owner, &collection);
 			T::Currency::unreserve(&collection_details.owner, collection_details.owner_deposit);
@@ -106,6 +115,27 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			let _ = ItemConfigOf::<T, I>::clear_prefix(&collection, witness.items, None);
 			let _ =
 				ItemAttributesApprovalsOf::<T, I>::clear_prefix(&collection, witness.items, None);
+			let _ =
+				ItemAttributeCountOf::<T, I>::clear_prefix(&collection, witness.attributes, None);
+			let _ = CollectionNamespaceAttributeCountOf::<T, I>::clear_prefix(&collection, 4, None);
+			let _ = AttributeSchemaOf::<T, I>::clear_prefix(
+				&collection,
+				T::MaxAttributeSchemas::get(),
+				None,
+			);
+			AttributeSchemasCountOf::<T, I>::remove(&collection);
+			CollectionAttributeEditorsOf::<T, I>::remove(&collection);
+			AttributeWritesInBlock::<T, I>::remove(&collection);
+			let _ = LockedAttributeKeysOf::<T, I>::clear_prefix(
+				(&collection,),
+				witness.attributes,
+				None,
+			);
+			let _ = ItemAttributesDelegateCount::<T, I>::clear_prefix(
+				(&collection,),
+				witness.attributes,
+				None,
+			);
 
 			Self::deposit_event(Event::Destroyed { collection });
 
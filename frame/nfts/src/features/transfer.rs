@@ -72,6 +72,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ItemPriceOf::<T, I>::remove(&collection, &item);
 		PendingSwapOf::<T, I>::remove(&collection, &item);
 
+		if T::AutoMoveItemAttributes::get() && origin != details.owner {
+			Self::on_item_transferred_attributes(collection, item, &origin, &details.owner);
+		}
+
 		Self::deposit_event(Event::Transferred {
 			collection,
 			item,
@@ -96,7 +100,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				return Ok(())
 			}
 
-			// Move the deposit to the new owner.
+			// Move the deposit to the new owner. The `owner_deposit` only holds what was reserved
+			// using the `T::Currency`, see `DepositHandler::uses_currency()`.
 			T::Currency::repatriate_reserved(
 				&details.owner,
 				&owner,
@@ -147,7 +152,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				return Ok(())
 			}
 
-			// Move the deposit to the new owner.
+			// Move the deposit to the new owner. The `owner_deposit` only holds what was reserved
+			// using the `T::Currency`, see `DepositHandler::uses_currency()`.
 			T::Currency::repatriate_reserved(
 				&details.owner,
 				&owner,
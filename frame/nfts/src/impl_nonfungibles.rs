@@ -58,7 +58,8 @@ impl<T: Config<I>, I: 'static> Inspect<<T as SystemConfig>::AccountId> for Palle
 			ItemMetadataOf::<T, I>::get(collection, item).map(|m| m.data.into())
 		} else {
 			let key = BoundedSlice::<_, _>::try_from(key).ok()?;
-			Attribute::<T, I>::get((collection, Some(item), namespace, key)).map(|a| a.0.into())
+			let (value, _) = Attribute::<T, I>::get((collection, Some(item), namespace, key))?;
+			Self::read_attribute_value(collection, Some(*item), namespace, key, value.into())
 		}
 	}
 
@@ -73,13 +74,19 @@ impl<T: Config<I>, I: 'static> Inspect<<T as SystemConfig>::AccountId> for Palle
 			CollectionMetadataOf::<T, I>::get(collection).map(|m| m.data.into())
 		} else {
 			let key = BoundedSlice::<_, _>::try_from(key).ok()?;
-			Attribute::<T, I>::get((
+			let (value, _) = Attribute::<T, I>::get((
 				collection,
 				Option::<T::ItemId>::None,
 				AttributeNamespace::CollectionOwner,
 				key,
-			))
-			.map(|a| a.0.into())
+			))?;
+			Self::read_attribute_value(
+				collection,
+				None,
+				&AttributeNamespace::CollectionOwner,
+				key,
+				value.into(),
+			)
 		}
 	}
 
@@ -195,6 +202,8 @@ impl<T: Config<I>, I: 'static> Mutate<<T as SystemConfig>::AccountId, ItemConfig
 			AttributeNamespace::Pallet,
 			Self::construct_attribute_key(key.to_vec())?,
 			Self::construct_attribute_value(value.to_vec())?,
+			false,
+			None,
 		)
 	}
 
@@ -223,6 +232,8 @@ impl<T: Config<I>, I: 'static> Mutate<<T as SystemConfig>::AccountId, ItemConfig
 			AttributeNamespace::Pallet,
 			Self::construct_attribute_key(key.to_vec())?,
 			Self::construct_attribute_value(value.to_vec())?,
+			false,
+			None,
 		)
 	}
 
@@ -36,12 +36,17 @@ pub mod mock;
 mod tests;
 
 mod common_functions;
+mod compression;
+mod deposit_handler;
 mod features;
 mod impl_nonfungibles;
+mod namespace_precedence;
 mod types;
 
 pub mod macros;
+pub mod migration;
 pub mod weights;
+pub mod well_known_keys;
 
 use codec::{Decode, Encode};
 use frame_support::traits::{
@@ -56,11 +61,15 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 
+pub use compression::*;
+pub use deposit_handler::*;
+pub use namespace_precedence::*;
 pub use pallet::*;
 pub use types::*;
 pub use weights::WeightInfo;
 
 type AccountIdLookupOf<T> = <<T as SystemConfig>::Lookup as StaticLookup>::Source;
+const LOG_TARGET: &str = "runtime::nfts";
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -69,8 +78,12 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::traits::{IdentifyAccount, Verify};
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -119,6 +132,15 @@ pub mod pallet {
 		/// Locker trait to enable Locking mechanism downstream.
 		type Locker: Locker<Self::CollectionId, Self::ItemId>;
 
+		/// Determines the attribute namespace to read from when it's not specified explicitly.
+		type NamespacePrecedence: NamespacePrecedence<Self, I>;
+
+		/// Reserves and returns the deposits of the attributes.
+		type AttributeDepositHandler: DepositHandler<Self, I>;
+
+		/// Handles the attribute deposits that couldn't be returned to their depositors on clear.
+		type OnLostDeposit: OnLostDeposit<Self, I>;
+
 		/// The basic amount of funds that must be reserved for collection.
 		#[pallet::constant]
 		type CollectionDeposit: Get<DepositBalanceOf<Self, I>>;
@@ -160,6 +182,15 @@ pub mod pallet {
 		#[pallet::constant]
 		type ItemAttributesApprovalsLimit: Get<u32>;
 
+		/// The number of remaining attributes approvals at which the item's owner gets warned
+		/// with the `ItemAttributesApprovalNearLimit` event.
+		#[pallet::constant]
+		type ItemAttributesApprovalsNearLimitThreshold: Get<u32>;
+
+		/// The maximum attribute editors a collection could have.
+		#[pallet::constant]
+		type CollectionAttributeEditorsLimit: Get<u32>;
+
 		/// The max number of tips a user could send.
 		#[pallet::constant]
 		type MaxTips: Get<u32>;
@@ -172,6 +203,55 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAttributesPerCall: Get<u32>;
 
+		/// The max number of attributes an item could hold across all the namespaces.
+		#[pallet::constant]
+		type MaxAttributesPerItem: Get<u32>;
+
+		/// The max number of chunks a large attribute's value could be split into.
+		#[pallet::constant]
+		type MaxAttributeChunks: Get<u32>;
+
+		/// Compresses the values of the attributes set with `set_compressed_attribute`.
+		type AttributeCompressor: AttributeCompressor;
+
+		/// The max number of attribute keys a collection could define the value schema for.
+		#[pallet::constant]
+		type MaxAttributeSchemas: Get<u32>;
+
+		/// The max number of options the `ValueConstraint::OneOf` schema could list.
+		#[pallet::constant]
+		type MaxAttributeValueOptions: Get<u32>;
+
+		/// Whether the deposits of the `ItemOwner` attributes should be moved to the new owner
+		/// whenever an item is transferred.
+		///
+		/// NOTE: the new owner takes over the deposits of up to `T::MaxAttributesPerItem`
+		/// attributes, which the previous owner is free to set right before the transfer, so the
+		/// buyers should expect a part of their funds to get reserved. The deposits the new owner
+		/// can't afford stay with the previous owner. The transfers are charged for moving
+		/// `T::MaxAttributesPerItem` deposits.
+		#[pallet::constant]
+		type AutoMoveItemAttributes: Get<bool>;
+
+		/// The min length of an attribute's value set by a regular account.
+		///
+		/// Setting it above zero prevents spamming the storage with empty attributes.
+		#[pallet::constant]
+		type MinAttributeValueLen: Get<u32>;
+
+		/// The attribute key prefixes only the collection's owner and the pallet could write.
+		///
+		/// Prevents the item owners and the delegated accounts from spoofing e.g. `sys:*` keys.
+		#[pallet::constant]
+		type ReservedKeyPrefixes: Get<Vec<Vec<u8>>>;
+
+		/// The max number of attribute writes per collection within a single block, if limited.
+		///
+		/// Protects the collections from a single account hammering the attribute writes. The
+		/// forced writes are not limited.
+		#[pallet::constant]
+		type MaxAttributeWritesPerBlock: Get<Option<u32>>;
+
 		/// Disables some of pallet's features.
 		#[pallet::constant]
 		type Features: Get<PalletFeatures>;
@@ -296,6 +376,62 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The types of attributes' values.
+	///
+	/// Attributes without a record here hold `AttributeValueType::Raw` values.
+	#[pallet::storage]
+	pub(super) type AttributeValueTypeOf<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, Option<T::ItemId>>,
+			NMapKey<Blake2_128Concat, AttributeNamespace<T::AccountId>>,
+			NMapKey<Blake2_128Concat, BoundedVec<u8, T::KeyLimit>>,
+		),
+		AttributeValueType,
+		OptionQuery,
+	>;
+
+	/// The blocks at which the attributes expire.
+	///
+	/// Attributes without a record here never expire.
+	#[pallet::storage]
+	pub(super) type AttributeExpiryOf<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, Option<T::ItemId>>,
+			NMapKey<Blake2_128Concat, AttributeNamespace<T::AccountId>>,
+			NMapKey<Blake2_128Concat, BoundedVec<u8, T::KeyLimit>>,
+		),
+		<T as SystemConfig>::BlockNumber,
+		OptionQuery,
+	>;
+
+	/// The blocks at which the attributes were last set.
+	///
+	/// Attributes without a record here were last set before the tracking was introduced.
+	#[pallet::storage]
+	pub(super) type AttributeLastSetOf<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, Option<T::ItemId>>,
+			NMapKey<Blake2_128Concat, AttributeNamespace<T::AccountId>>,
+			NMapKey<Blake2_128Concat, BoundedVec<u8, T::KeyLimit>>,
+		),
+		<T as SystemConfig>::BlockNumber,
+		ValueQuery,
+	>;
+
+	/// The number of attribute writes into a collection made within the current block.
+	///
+	/// Only tracked when the `T::MaxAttributeWritesPerBlock` is set. Cleared at the beginning of
+	/// each block.
+	#[pallet::storage]
+	pub(super) type AttributeWritesInBlock<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, u32, ValueQuery>;
+
 	/// A price of an item.
 	#[pallet::storage]
 	pub(super) type ItemPriceOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -320,6 +456,89 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The accounts allowed to set attributes within their own `Account` namespace of any item
+	/// of the collection without the per-item approval.
+	#[pallet::storage]
+	pub(super) type CollectionAttributeEditorsOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		CollectionAttributeEditors<T, I>,
+		ValueQuery,
+	>;
+
+	/// The number of attributes an item holds across all the namespaces.
+	#[pallet::storage]
+	pub(super) type ItemAttributeCountOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The number of attributes stored within a collection per kind of the namespace.
+	#[pallet::storage]
+	pub(super) type CollectionNamespaceAttributeCountOf<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<
+			_,
+			Blake2_128Concat,
+			T::CollectionId,
+			Blake2_128Concat,
+			NamespaceDiscriminant,
+			u32,
+			ValueQuery,
+		>;
+
+	/// The constraints the values of the `CollectionOwner` namespace attributes of a collection
+	/// must satisfy, by the attribute's key.
+	#[pallet::storage]
+	pub(super) type AttributeSchemaOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::KeyLimit>,
+		ValueConstraintOf<T, I>,
+		OptionQuery,
+	>;
+
+	/// The number of attribute keys a collection has defined the value schema for.
+	#[pallet::storage]
+	pub(super) type AttributeSchemasCountOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, u32, ValueQuery>;
+
+	/// The keys of the `CollectionOwner` namespace attributes locked by the collection's owner.
+	#[pallet::storage]
+	pub(super) type LockedAttributeKeysOf<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, Option<T::ItemId>>,
+			NMapKey<Blake2_128Concat, BoundedVec<u8, T::KeyLimit>>,
+		),
+		(),
+		OptionQuery,
+	>;
+
+	/// The number of attributes set by a delegated account within its namespace of an item.
+	///
+	/// Missing for the accounts whose attributes were set before the counter was introduced, their
+	/// attributes are counted on the first access instead.
+	#[pallet::storage]
+	pub(super) type ItemAttributesDelegateCount<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+			NMapKey<Blake2_128Concat, T::AccountId>, // delegate
+		),
+		u32,
+		OptionQuery,
+	>;
+
 	/// Stores the `CollectionId` that is going to be used for the next collection.
 	/// This gets incremented whenever a new collection is created.
 	#[pallet::storage]
@@ -444,6 +663,19 @@ pub mod pallet {
 			key: BoundedVec<u8, T::KeyLimit>,
 			value: BoundedVec<u8, T::ValueLimit>,
 			namespace: AttributeNamespace<T::AccountId>,
+			old_value: Option<BoundedVec<u8, T::ValueLimit>>,
+		},
+		/// The deposit the `depositor` holds for the attribute has changed by the `delta`, which
+		/// was either `reserved` or returned, and now amounts to the `deposit`.
+		AttributeDepositChanged {
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+			depositor: T::AccountId,
+			delta: DepositBalanceOf<T, I>,
+			reserved: bool,
+			deposit: DepositBalanceOf<T, I>,
 		},
 		/// Attribute metadata has been cleared for a `collection` or `item`.
 		AttributeCleared {
@@ -451,6 +683,8 @@ pub mod pallet {
 			maybe_item: Option<T::ItemId>,
 			key: BoundedVec<u8, T::KeyLimit>,
 			namespace: AttributeNamespace<T::AccountId>,
+			value: BoundedVec<u8, T::ValueLimit>,
+			deposit: DepositBalanceOf<T, I>,
 		},
 		/// A new approval to modify item attributes was added.
 		ItemAttributesApprovalAdded {
@@ -459,10 +693,22 @@ pub mod pallet {
 			delegate: T::AccountId,
 		},
 		/// A new approval to modify item attributes was removed.
+		///
+		/// The `delegate`'s attributes were cleared and the deposits the `delegate` paid for them
+		/// were returned. The `deposit_refunded` is the total amount actually unreserved, thus
+		/// excludes the part that couldn't be returned and was passed to the `OnLostDeposit`.
 		ItemAttributesApprovalRemoved {
 			collection: T::CollectionId,
 			item: T::ItemId,
 			delegate: T::AccountId,
+			attributes_removed: u32,
+			deposit_refunded: DepositBalanceOf<T, I>,
+		},
+		/// The item's attributes approvals are about to reach the `ItemAttributesApprovalsLimit`.
+		ItemAttributesApprovalNearLimit {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			remaining: u32,
 		},
 		/// Ownership acceptance has changed for an account.
 		OwnershipAcceptanceChanged { who: T::AccountId, maybe_collection: Option<T::CollectionId> },
@@ -532,6 +778,95 @@ pub mod pallet {
 			item: T::ItemId,
 			namespace: AttributeNamespace<T::AccountId>,
 		},
+		/// The deposit of an attribute has been recalculated.
+		AttributeDepositUpdated {
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+			old_deposit: DepositBalanceOf<T, I>,
+			new_deposit: DepositBalanceOf<T, I>,
+		},
+		/// All the attributes within the `namespace` have been cleared for an `item`.
+		ItemNamespaceCleared {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			namespace: AttributeNamespace<T::AccountId>,
+			attributes: u32,
+		},
+		/// The deposits of the `item`'s `ItemOwner` attributes have been moved from the previous
+		/// owner to the current one.
+		ItemAttributesTransferred {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			from: T::AccountId,
+			to: T::AccountId,
+			attributes: u32,
+		},
+		/// The deposit of the `item`'s `ItemOwner` attribute couldn't be moved to the new owner on
+		/// transfer, so it stays reserved from the previous one.
+		ItemAttributeDepositNotMoved {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			key: BoundedVec<u8, T::KeyLimit>,
+			from: T::AccountId,
+			to: T::AccountId,
+		},
+		/// The attributes left after the `item` had been burned were cleared.
+		OrphanedAttributesCleared { collection: T::CollectionId, item: T::ItemId, attributes: u32 },
+		/// A batch of the `collection`'s attributes was cleared, `remaining` attributes are left.
+		CollectionAttributesCleared { collection: T::CollectionId, attributes: u32, remaining: u32 },
+		/// The `item` owner has started or stopped sponsoring the attribute deposits of the
+		/// delegated accounts.
+		DelegateAttributesSponsorshipSet {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			sponsor: bool,
+		},
+		/// The deposit for the attribute set by the `delegate` was paid by the `sponsor`.
+		AttributeDepositSponsored {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			key: BoundedVec<u8, T::KeyLimit>,
+			delegate: T::AccountId,
+			sponsor: T::AccountId,
+		},
+		/// The value schema of the `key` attribute was set, `None` means it was removed.
+		AttributeSchemaSet {
+			collection: T::CollectionId,
+			key: BoundedVec<u8, T::KeyLimit>,
+			constraint: Option<ValueConstraintOf<T, I>>,
+		},
+		/// The `key` attribute within the `CollectionOwner` namespace was locked.
+		AttributeKeyLocked {
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+		},
+		/// The attribute was set to expire at the `expiry` block.
+		AttributeExpirySet {
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+			expiry: <T as SystemConfig>::BlockNumber,
+		},
+		/// The attribute was force-set on behalf of another account, so its `deposit` was returned
+		/// to the previous depositor. `None` means the collection's owner in the `CollectionOwner`
+		/// namespace.
+		AttributeDepositReassigned {
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+			from: Option<T::AccountId>,
+			to: Option<T::AccountId>,
+			deposit: DepositBalanceOf<T, I>,
+		},
+		/// The `editor` is allowed to set attributes of any item of the `collection`.
+		CollectionAttributeEditorAdded { collection: T::CollectionId, editor: T::AccountId },
+		/// The `editor` is no longer allowed to set attributes of the `collection`'s items.
+		CollectionAttributeEditorRemoved { collection: T::CollectionId, editor: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -622,6 +957,63 @@ pub mod pallet {
 		MaxAttributesLimitReached,
 		/// The provided namespace isn't supported in this call.
 		WrongNamespace,
+		/// The item's config is missing, while the collection's attributes are locked.
+		CannotInferLockState,
+		/// The item still exists, so its attributes are not orphaned.
+		ItemNotBurned,
+		/// The item has reached the max number of attributes.
+		MaxAttributesReached,
+		/// The empty attribute key is reserved for the metadata.
+		EmptyKey,
+		/// The large attribute's value is split into zero or too many chunks.
+		InvalidChunksNumber,
+		/// The attribute's key is locked by the collection's owner.
+		AttributeLocked,
+		/// The attribute's value doesn't satisfy the schema defined for its key.
+		SchemaViolation,
+		/// The collection has reached the limit of attribute schemas.
+		MaxAttributeSchemasReached,
+		/// The attribute's expiry block has already passed.
+		InvalidExpiry,
+		/// The attribute doesn't expire or hasn't expired yet.
+		AttributeNotExpired,
+		/// The attribute's value is shorter than the `T::MinAttributeValueLen`.
+		ValueTooShort,
+		/// The attribute's key starts with a prefix reserved for the collection's owner.
+		ReservedKey,
+		/// The collection has reached the limit of attribute writes within the current block.
+		RateLimited,
+		/// The attribute's current value differs from the expected one.
+		PreconditionFailed,
+		/// The large attribute split into chunks can't be moved to another namespace.
+		LargeAttributeNotMovable,
+		/// The segment of the attribute key's path is empty or contains the separator.
+		InvalidPathSegment,
+		/// The attribute key's path exceeds the `KeyLimit`.
+		PathTooLong,
+		/// The collection has reached the maximum number of attribute editors.
+		ReachedCollectionAttributeEditorsLimit,
+		/// The account isn't an attribute editor of the collection.
+		NotCollectionAttributeEditor,
+		/// The attribute's value can't be compressed.
+		CompressionUnsupported,
+		/// The attribute's value is too long, either before or after the compression.
+		CompressedValueTooLong,
+		/// The collection still has some items.
+		CollectionNotEmpty,
+		/// The value type is reserved for the attributes set by the pallet itself.
+		ReservedValueType,
+		/// The large attribute can only be cleared along with its chunks.
+		LargeAttributeNotClearable,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// only the collections written into within the previous block are tracked
+			let removed = AttributeWritesInBlock::<T, I>::clear(u32::MAX, None);
+			T::DbWeight::get().reads_writes(removed.loops.into(), removed.unique.into())
+		}
 	}
 
 	#[pallet::call]
@@ -936,9 +1328,12 @@ pub mod pallet {
 		///
 		/// Emits `Transferred`.
 		///
-		/// Weight: `O(1)`
+		/// Weight: `O(1)`, or `O(T::MaxAttributesPerItem)` if `T::AutoMoveItemAttributes` is
+		/// enabled.
 		#[pallet::call_index(6)]
-		#[pallet::weight(T::WeightInfo::transfer())]
+		#[pallet::weight(
+			T::WeightInfo::transfer().saturating_add(Pallet::<T, I>::moved_item_attributes_weight())
+		)]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -1340,7 +1735,7 @@ pub mod pallet {
 		/// - `key`: The key of the attribute.
 		/// - `value`: The value to which to set the attribute.
 		///
-		/// Emits `AttributeSet`.
+		/// Emits `AttributeSet`, unless the attribute already has the same `value`.
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(19)]
@@ -1378,8 +1773,10 @@ pub mod pallet {
 		/// - `namespace`: Attribute's namespace.
 		/// - `key`: The key of the attribute.
 		/// - `value`: The value to which to set the attribute.
+		/// - `preserve_deposit`: Whether to keep the existing deposit when it was paid by `set_as`.
 		///
-		/// Emits `AttributeSet`.
+		/// Emits `AttributeSet`, as well as `AttributeDepositReassigned` if the attribute was set
+		/// by another account.
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(20)]
@@ -1392,9 +1789,66 @@ pub mod pallet {
 			namespace: AttributeNamespace<T::AccountId>,
 			key: BoundedVec<u8, T::KeyLimit>,
 			value: BoundedVec<u8, T::ValueLimit>,
+			preserve_deposit: bool,
 		) -> DispatchResult {
 			T::ForceOrigin::ensure_origin(origin)?;
-			Self::do_force_set_attribute(set_as, collection, maybe_item, namespace, key, value)
+			Self::do_force_set_attribute(
+				set_as,
+				collection,
+				maybe_item,
+				namespace,
+				key,
+				value,
+				preserve_deposit,
+				None,
+			)
+		}
+
+		/// Set an attribute for a collection or item along with the type of its value.
+		///
+		/// Follows the same rules as `set_attribute`, the `value_type` is only a hint for the
+		/// consumers on how to interpret the `value`. The `ChunkManifest` and `Compressed` types
+		/// are reserved for `set_large_attribute` and `set_compressed_attribute`.
+		///
+		/// - `collection`: The identifier of the collection whose item's metadata to set.
+		/// - `maybe_item`: The identifier of the item whose metadata to set.
+		/// - `namespace`: Attribute's namespace.
+		/// - `key`: The key of the attribute.
+		/// - `value`: The value to which to set the attribute.
+		/// - `value_type`: The type of the `value`.
+		///
+		/// Emits `AttributeSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_attribute())]
+		pub fn set_typed_attribute(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+			value: BoundedVec<u8, T::ValueLimit>,
+			value_type: AttributeValueType,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			ensure!(
+				!matches!(
+					value_type,
+					AttributeValueType::ChunkManifest | AttributeValueType::Compressed
+				),
+				Error::<T, I>::ReservedValueType
+			);
+			Self::do_set_typed_attribute(
+				origin.clone(),
+				collection,
+				maybe_item,
+				namespace,
+				key,
+				value,
+				value_type,
+				origin,
+			)
 		}
 
 		/// Clear an attribute for a collection or item.
@@ -1436,6 +1890,7 @@ pub mod pallet {
 		/// - `delegate`: The account to delegate permission to change attributes of the item.
 		///
 		/// Emits `ItemAttributesApprovalAdded` on success.
+		/// Emits `ItemAttributesApprovalNearLimit` if the approvals are about to reach the limit.
 		#[pallet::call_index(22)]
 		#[pallet::weight(T::WeightInfo::approve_item_attributes())]
 		pub fn approve_item_attributes(
@@ -1457,11 +1912,16 @@ pub mod pallet {
 		/// - `collection`: Collection that the item is contained within.
 		/// - `item`: The item that holds attributes.
 		/// - `delegate`: The previously approved account to remove.
+		/// - `witness`: The amount of the `delegate`'s attributes. Ignored, the amount is tracked
+		/// within the storage.
 		///
 		/// Emits `ItemAttributesApprovalRemoved` on success.
+		///
+		/// Weight: `O(T::MaxAttributesPerItem)`, refunded to `O(A)` where `A` is the number of the
+		/// removed attributes.
 		#[pallet::call_index(23)]
 		#[pallet::weight(T::WeightInfo::cancel_item_attributes_approval(
-			witness.account_attributes
+			T::MaxAttributesPerItem::get()
 		))]
 		pub fn cancel_item_attributes_approval(
 			origin: OriginFor<T>,
@@ -1469,10 +1929,14 @@ pub mod pallet {
 			item: T::ItemId,
 			delegate: AccountIdLookupOf<T>,
 			witness: CancelAttributesApprovalWitness,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
 			let delegate = T::Lookup::lookup(delegate)?;
-			Self::do_cancel_item_attributes_approval(origin, collection, item, delegate, witness)
+			// kept for the compatibility of the call's encoding only
+			let _ = witness;
+			let attributes =
+				Self::do_cancel_item_attributes_approval(origin, collection, item, delegate)?;
+			Ok(Some(T::WeightInfo::cancel_item_attributes_approval(attributes)).into())
 		}
 
 		/// Set the metadata for an item.
@@ -1684,9 +2148,15 @@ pub mod pallet {
 		/// - `item`: The item the sender wants to buy.
 		/// - `bid_price`: The price the sender is willing to pay.
 		///
+		/// NOTE: if `T::AutoMoveItemAttributes` is enabled, the sender also takes over the
+		/// deposits the seller paid for the item's `ItemOwner` attributes, as long as the sender
+		/// can afford them.
+		///
 		/// Emits `ItemBought` on success.
 		#[pallet::call_index(32)]
-		#[pallet::weight(T::WeightInfo::buy_item())]
+		#[pallet::weight(
+			T::WeightInfo::buy_item().saturating_add(Pallet::<T, I>::moved_item_attributes_weight())
+		)]
 		pub fn buy_item(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -1784,9 +2254,15 @@ pub mod pallet {
 		/// - `receive_item`: The item to be received.
 		/// - `witness_price`: A price that was previously agreed on.
 		///
+		/// NOTE: if `T::AutoMoveItemAttributes` is enabled, each side also takes over the
+		/// deposits the other side paid for the received item's `ItemOwner` attributes, as long as
+		/// it can afford them.
+		///
 		/// Emits `SwapClaimed` on success.
 		#[pallet::call_index(36)]
-		#[pallet::weight(T::WeightInfo::claim_swap())]
+		#[pallet::weight(T::WeightInfo::claim_swap().saturating_add(
+			Pallet::<T, I>::moved_item_attributes_weight().saturating_mul(2)
+		))]
 		pub fn claim_swap(
 			origin: OriginFor<T>,
 			send_collection: T::CollectionId,
@@ -1845,6 +2321,7 @@ pub mod pallet {
 		///
 		/// Emits `AttributeSet` for each provided attribute.
 		/// Emits `ItemAttributesApprovalAdded` if the approval wasn't set before.
+		/// Emits `ItemAttributesApprovalNearLimit` if the approvals are about to reach the limit.
 		/// Emits `PreSignedAttributesSet` on success.
 		#[pallet::call_index(38)]
 		#[pallet::weight(T::WeightInfo::set_attributes_pre_signed(data.attributes.len() as u32))]
@@ -1859,6 +2336,741 @@ pub mod pallet {
 			ensure!(signature.verify(&*msg, &signer), Error::<T, I>::WrongSignature);
 			Self::do_set_attributes_pre_signed(origin, data, signer)
 		}
+
+		/// Recalculate the deposit of an attribute according to the current deposit parameters.
+		///
+		/// Origin must be Signed and must be either the account that paid the deposit or the
+		/// owner of the `collection`.
+		///
+		/// In case the current deposit is lower than the reserved one, the difference is returned
+		/// to the depositor.
+		///
+		/// - `collection`: The identifier of the collection whose item's attribute to update.
+		/// - `maybe_item`: The identifier of the item whose attribute to update.
+		/// - `namespace`: Attribute's namespace.
+		/// - `key`: The key of the attribute.
+		///
+		/// Emits `AttributeDepositUpdated` if the deposit was changed.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::recalculate_attribute_deposit())]
+		pub fn recalculate_attribute_deposit(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_recalculate_attribute_deposit(origin, collection, maybe_item, namespace, key)
+		}
+
+		/// Clear all the attributes of an item within the namespace.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be allowed to modify
+		/// the `namespace`.
+		///
+		/// Any deposit is freed for the accounts that paid it.
+		///
+		/// - `collection`: The identifier of the collection whose item's attributes to clear.
+		/// - `item`: The identifier of the item whose attributes to clear.
+		/// - `namespace`: Attributes' namespace.
+		/// - `witness`: The amount of attributes stored within the `namespace`.
+		///
+		/// Emits `ItemNamespaceCleared`.
+		///
+		/// Weight: `O(A)` where `A` is the number of attributes within the namespace.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::clear_item_namespace(witness.attributes))]
+		pub fn clear_item_namespace(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			namespace: AttributeNamespace<T::AccountId>,
+			witness: ClearItemNamespaceWitness,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_clear_item_namespace(maybe_check_owner, collection, item, namespace, witness)
+		}
+
+		/// Take over the `ItemOwner` attributes of an item set by its previous owner.
+		///
+		/// Origin must be Signed and must be the current owner of the `item`.
+		///
+		/// The deposits of the attributes within the `ItemOwner` namespace that were paid by
+		/// `from` get reserved from the sender and returned to `from`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item whose attributes to take over.
+		/// - `from`: The previous owner of the item that paid the deposits.
+		/// - `witness`: The amount of attributes stored within the `ItemOwner` namespace.
+		///
+		/// Emits `ItemAttributesTransferred`.
+		///
+		/// Weight: `O(A)` where `A` is the number of attributes within the `ItemOwner` namespace.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::transfer_item_attributes(witness.attributes))]
+		pub fn transfer_item_attributes(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			from: AccountIdLookupOf<T>,
+			witness: TransferItemAttributesWitness,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let from = T::Lookup::lookup(from)?;
+			Self::do_transfer_item_attributes(from, origin, collection, item, witness)
+		}
+
+		/// Clear the attributes left after an item had been burned.
+		///
+		/// Origin must be Signed.
+		///
+		/// Any deposit is freed for the accounts that paid it. The attributes within the
+		/// `CollectionOwner` namespace are kept when the collection's attributes are locked.
+		///
+		/// - `collection`: The collection of the burned item.
+		/// - `item`: The burned item whose attributes to clear.
+		/// - `witness`: The amount of attributes stored for the item across all the namespaces.
+		///
+		/// Emits `OrphanedAttributesCleared`.
+		///
+		/// Weight: `O(A)` where `A` is the number of the item's attributes.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::clear_orphaned_attributes(witness.attributes))]
+		pub fn clear_orphaned_attributes(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			witness: ClearOrphanedAttributesWitness,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_clear_orphaned_attributes(collection, item, witness)
+		}
+
+		/// Cancel the previously provided approval to change item's attributes without providing
+		/// the witness data.
+		/// All the previously set attributes by the `delegate` will be removed.
+		///
+		/// Origin must be Signed and must be an owner of the `item`.
+		///
+		/// Same as `cancel_item_attributes_approval`, the number of the `delegate`'s attributes is
+		/// taken from the storage and the unused weight of the worst case of
+		/// `T::MaxAttributesPerItem` attributes is refunded.
+		///
+		/// - `collection`: Collection that the item is contained within.
+		/// - `item`: The item that holds attributes.
+		/// - `delegate`: The previously approved account to remove.
+		///
+		/// Emits `ItemAttributesApprovalRemoved` on success.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::cancel_item_attributes_approval(
+			T::MaxAttributesPerItem::get()
+		))]
+		pub fn cancel_item_attributes_approval_exact(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			delegate: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			let attributes =
+				Self::do_cancel_item_attributes_approval(origin, collection, item, delegate)?;
+			Ok(Some(T::WeightInfo::cancel_item_attributes_approval(attributes)).into())
+		}
+
+		/// Start or stop paying the deposits for the attributes set by the delegated accounts
+		/// within their namespaces of an item.
+		///
+		/// Origin must be Signed and must be an owner of the `item`.
+		///
+		/// - `collection`: Collection that the item is contained within.
+		/// - `item`: The item that holds attributes.
+		/// - `sponsor`: Whether the item's owner should pay the delegates' deposits.
+		///
+		/// NOTE: the deposits that were already paid are not moved.
+		///
+		/// Emits `DelegateAttributesSponsorshipSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::set_delegate_attributes_sponsorship())]
+		pub fn set_delegate_attributes_sponsorship(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			sponsor: bool,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_delegate_attributes_sponsorship(origin, collection, item, sponsor)
+		}
+
+		/// Set an attribute whose value exceeds the `T::ValueLimit` for a collection or item.
+		///
+		/// Follows the same rules as `set_attribute`. Every chunk of the value is stored as a
+		/// separate attribute with the key derived from the `key` and the chunk's index, which
+		/// requires a deposit of its own. The `key` attribute holds the number of chunks.
+		///
+		/// - `collection`: The identifier of the collection whose item's metadata to set.
+		/// - `maybe_item`: The identifier of the item whose metadata to set.
+		/// - `namespace`: Attribute's namespace.
+		/// - `key`: The key of the attribute.
+		/// - `chunks`: The chunks of the value.
+		///
+		/// Emits `AttributeSet` for every chunk and for the `key` attribute.
+		///
+		/// Weight: `O(C)` where `C` is `T::MaxAttributeChunks`.
+		#[pallet::call_index(46)]
+		#[pallet::weight(
+			T::WeightInfo::set_attribute()
+				.saturating_mul(chunks.len().saturating_add(1) as u64)
+				.saturating_add(
+					T::WeightInfo::clear_attribute()
+						.saturating_mul(T::MaxAttributeChunks::get() as u64)
+				)
+		)]
+		pub fn set_large_attribute(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+			chunks: BoundedVec<BoundedVec<u8, T::ValueLimit>, T::MaxAttributeChunks>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_large_attribute(
+				origin.clone(),
+				collection,
+				maybe_item,
+				namespace,
+				key,
+				chunks.into_inner(),
+				origin,
+			)
+		}
+
+		/// Clear an attribute previously set with `set_large_attribute` along with all its
+		/// chunks.
+		///
+		/// Follows the same rules as `clear_attribute`.
+		///
+		/// - `collection`: The identifier of the collection whose item's metadata to clear.
+		/// - `maybe_item`: The identifier of the item whose metadata to clear.
+		/// - `namespace`: Attribute's namespace.
+		/// - `key`: The key of the attribute.
+		///
+		/// Emits `AttributeCleared` for every chunk and for the `key` attribute.
+		///
+		/// Weight: `O(C)` where `C` is `T::MaxAttributeChunks`.
+		#[pallet::call_index(47)]
+		#[pallet::weight(
+			T::WeightInfo::clear_attribute()
+				.saturating_mul(T::MaxAttributeChunks::get().saturating_add(1) as u64)
+		)]
+		pub fn clear_large_attribute(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_clear_large_attribute(
+				maybe_check_owner,
+				collection,
+				maybe_item,
+				namespace,
+				key,
+			)
+		}
+
+		/// Lock the attribute of a collection or item within the `CollectionOwner` namespace, so
+		/// it can't be changed or cleared anymore while the other attributes stay editable.
+		///
+		/// Origin must be Signed and must be the owner of the `collection`.
+		///
+		/// NOTE: locking is irreversible, the attribute can't be unlocked even by the
+		/// `ForceOrigin`.
+		///
+		/// - `collection`: The identifier of the collection whose attribute to lock.
+		/// - `maybe_item`: The identifier of the item whose attribute to lock.
+		/// - `key`: The key of the existing attribute.
+		///
+		/// Emits `AttributeKeyLocked`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::lock_attribute())]
+		pub fn lock_attribute(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_lock_attribute(origin, collection, maybe_item, key)
+		}
+
+		/// Set or remove the constraint the values of the `key` attribute within the
+		/// `CollectionOwner` namespace must satisfy.
+		///
+		/// Origin must be Signed and must be the owner of the `collection`.
+		///
+		/// NOTE: the schema applies only to the values set afterwards, the existing value of the
+		/// attribute isn't validated.
+		///
+		/// - `collection`: The identifier of the collection to set the schema for.
+		/// - `key`: The key of the attribute.
+		/// - `constraint`: The constraint of the attribute's value, `None` removes the schema.
+		///
+		/// Emits `AttributeSchemaSet`.
+		///
+		/// Weight: `O(N)` where `N` is the number of the `ValueConstraint::OneOf` options.
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::set_attribute_schema(
+			constraint.as_ref().map_or(0, ValueConstraint::options_len)
+		))]
+		pub fn set_attribute_schema(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			key: BoundedVec<u8, T::KeyLimit>,
+			constraint: Option<ValueConstraintOf<T, I>>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_attribute_schema(origin, collection, key, constraint)
+		}
+
+		/// Clear an item's attribute from the namespace it is resolved from according to the
+		/// `T::NamespacePrecedence`.
+		///
+		/// Follows the same rules as `clear_attribute` for the resolved namespace.
+		///
+		/// - `collection`: The identifier of the collection whose item's attribute to clear.
+		/// - `item`: The identifier of the item whose attribute to clear.
+		/// - `key`: The key of the attribute.
+		///
+		/// Emits `AttributeCleared` with the resolved namespace.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::clear_attribute())]
+		pub fn clear_resolved_attribute(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			key: BoundedVec<u8, T::KeyLimit>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_clear_resolved_attribute(maybe_check_owner, collection, item, key)
+		}
+
+		/// Set multiple attributes for a collection or item within the same namespace at once.
+		///
+		/// Follows the same rules as `set_attribute` for every attribute, the deposits are
+		/// reserved from the sender.
+		///
+		/// - `collection`: The identifier of the collection whose item's attributes to set.
+		/// - `maybe_item`: The identifier of the item whose attributes to set.
+		/// - `namespace`: Attributes' namespace.
+		/// - `attributes`: The key-value pairs of the attributes, up to `T::MaxAttributesPerCall`.
+		///
+		/// Emits `AttributeSet` for each provided attribute.
+		///
+		/// Weight: `O(N)` where `N` is the number of `attributes`.
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::set_attributes(attributes.len() as u32))]
+		pub fn set_attributes(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			attributes: BoundedVec<
+				(BoundedVec<u8, T::KeyLimit>, BoundedVec<u8, T::ValueLimit>),
+				T::MaxAttributesPerCall,
+			>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_attributes(
+				origin.clone(),
+				collection,
+				maybe_item,
+				namespace,
+				attributes.into_inner(),
+				origin,
+			)
+		}
+
+		/// Set an attribute for a collection or item that expires at the `expiry` block.
+		///
+		/// Follows the same rules as `set_attribute`. Once expired, the attribute is no longer
+		/// returned by the `attribute` getters and can be removed by anyone with
+		/// `reap_expired_attribute`.
+		///
+		/// - `collection`: The identifier of the collection whose item's attribute to set.
+		/// - `maybe_item`: The identifier of the item whose attribute to set.
+		/// - `namespace`: Attribute's namespace.
+		/// - `key`: The key of the attribute.
+		/// - `value`: The value to which to set the attribute.
+		/// - `expiry`: The block at which the attribute expires, must be in the future.
+		///
+		/// Emits `AttributeSet` and `AttributeExpirySet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::set_attribute())]
+		pub fn set_attribute_with_expiry(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+			value: BoundedVec<u8, T::ValueLimit>,
+			expiry: <T as SystemConfig>::BlockNumber,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_attribute_with_expiry(
+				origin.clone(),
+				collection,
+				maybe_item,
+				namespace,
+				key,
+				value,
+				expiry,
+				origin,
+			)
+		}
+
+		/// Remove an expired attribute and refund its deposit.
+		///
+		/// Origin must be Signed, any account may reap an expired attribute.
+		///
+		/// - `collection`: The identifier of the collection whose item's attribute to remove.
+		/// - `maybe_item`: The identifier of the item whose attribute to remove.
+		/// - `namespace`: Attribute's namespace.
+		/// - `key`: The key of the expired attribute.
+		///
+		/// Emits `AttributeCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(53)]
+		#[pallet::weight(T::WeightInfo::clear_attribute())]
+		pub fn reap_expired_attribute(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_reap_expired_attribute(collection, maybe_item, namespace, key)
+		}
+
+		/// Approve item's attributes to be changed by multiple delegated third-party accounts.
+		///
+		/// Origin must be Signed and must be an owner of the `item`.
+		///
+		/// - `collection`: A collection of the item.
+		/// - `item`: The item that holds attributes.
+		/// - `delegates`: The accounts to delegate permission to change attributes of the item.
+		///
+		/// Nothing gets approved if the approvals limit would be exceeded.
+		///
+		/// Emits `ItemAttributesApprovalAdded` for each of the `delegates`.
+		/// Emits `ItemAttributesApprovalNearLimit` if the approvals are about to reach the limit.
+		///
+		/// Weight: `O(N)` where `N` is the number of `delegates`.
+		#[pallet::call_index(54)]
+		#[pallet::weight(
+			T::WeightInfo::approve_item_attributes().saturating_mul(delegates.len() as u64)
+		)]
+		pub fn approve_item_attributes_batch(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			delegates: BoundedVec<AccountIdLookupOf<T>, T::ItemAttributesApprovalsLimit>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let delegates = delegates
+				.into_iter()
+				.map(T::Lookup::lookup)
+				.collect::<Result<Vec<_>, _>>()?
+				.try_into()
+				.map_err(|_| Error::<T, I>::ReachedApprovalLimit)?;
+			Self::do_approve_item_attributes_batch(origin, collection, item, delegates)
+		}
+
+		/// Force-clear an attribute for a collection or item.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// Unlike `clear_attribute`, clears the attribute even if it's locked. The deposit is
+		/// returned to the account that paid it.
+		///
+		/// - `collection`: The identifier of the collection whose item's metadata to clear.
+		/// - `maybe_item`: The identifier of the item whose metadata to clear.
+		/// - `namespace`: Attribute's namespace.
+		/// - `key`: The key of the attribute.
+		///
+		/// Emits `AttributeCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(55)]
+		#[pallet::weight(T::WeightInfo::clear_attribute())]
+		pub fn force_clear_attribute(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_force_clear_attribute(collection, maybe_item, namespace, key)
+		}
+
+		/// Set an attribute for a collection or item only if its current value is the `expected`
+		/// one.
+		///
+		/// Follows the same rules as `set_attribute`. Lets the accounts editing the same attributes
+		/// make sure they don't overwrite each other's changes.
+		///
+		/// - `collection`: The identifier of the collection whose item's attribute to set.
+		/// - `maybe_item`: The identifier of the item whose attribute to set.
+		/// - `namespace`: Attribute's namespace.
+		/// - `key`: The key of the attribute.
+		/// - `expected`: The expected current value of the attribute, `None` means the attribute
+		///   must not exist.
+		/// - `value`: The value to which to set the attribute.
+		///
+		/// Emits `AttributeSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(56)]
+		#[pallet::weight(T::WeightInfo::set_attribute())]
+		pub fn set_attribute_if(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+			expected: Option<BoundedVec<u8, T::ValueLimit>>,
+			value: BoundedVec<u8, T::ValueLimit>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_attribute_if(
+				origin.clone(),
+				collection,
+				maybe_item,
+				namespace,
+				key,
+				expected,
+				value,
+				origin,
+			)
+		}
+
+		/// Move an item's attribute from one namespace to another.
+		///
+		/// Origin must be Signed and must be allowed both to clear the attribute within the
+		/// `from_namespace` and to set it within the `to_namespace`, following the same rules as
+		/// `clear_attribute` and `set_attribute`.
+		///
+		/// The deposit is returned to the account that paid it and the deposit for the moved
+		/// attribute is reserved from the origin. The expiry of the attribute isn't carried over
+		/// and the large attributes split into chunks can't be moved.
+		///
+		/// - `collection`: The identifier of the collection whose item's attribute to move.
+		/// - `item`: The identifier of the item whose attribute to move.
+		/// - `from_namespace`: The namespace to move the attribute from.
+		/// - `to_namespace`: The namespace to move the attribute to.
+		/// - `key`: The key of the attribute.
+		///
+		/// Emits `AttributeCleared` and `AttributeSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(57)]
+		#[pallet::weight(
+			T::WeightInfo::clear_attribute().saturating_add(T::WeightInfo::set_attribute())
+		)]
+		pub fn move_attribute(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			from_namespace: AttributeNamespace<T::AccountId>,
+			to_namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_move_attribute(origin, collection, item, from_namespace, to_namespace, key)
+		}
+
+		/// Clear a batch of the collection's attributes across all the items and namespaces.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the owner of the
+		/// `collection`. The owner can only clear the attributes once all the collection's items
+		/// are burned.
+		///
+		/// Any deposit is freed for the accounts that paid it. Like `destroy`, ignores the
+		/// attribute locks, so the collections with too many attributes to be destroyed at once
+		/// could be cleaned up upfront by repeating the call until no attributes remain.
+		///
+		/// - `collection`: The identifier of the collection whose attributes to clear.
+		/// - `witness`: The max amount of attributes to clear within the call.
+		///
+		/// Emits `CollectionAttributesCleared` with the amount of the remaining attributes.
+		///
+		/// Weight: `O(A)` where `A = witness.attributes`.
+		#[pallet::call_index(58)]
+		#[pallet::weight(T::WeightInfo::clear_collection_attributes(witness.attributes))]
+		pub fn clear_collection_attributes(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			witness: AttributeCleanupWitness,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_clear_all_collection_attributes(maybe_check_owner, collection, witness)
+				.map(|_| ())
+		}
+
+		/// Force-set an attribute for a collection or item reserving the given deposit for it.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// Any existing deposit of the attribute is returned to the account that paid it before
+		/// the `amount` is reserved from `set_as`. The collection owner's deposit within the
+		/// `CollectionOwner` namespace is accounted within the collection's owner deposit, same as
+		/// for `set_attribute`.
+		///
+		/// - `set_as`: The owner of the attribute to reserve the deposit from.
+		/// - `collection`: The identifier of the collection whose item's metadata to set.
+		/// - `maybe_item`: The identifier of the item whose metadata to set.
+		/// - `namespace`: Attribute's namespace.
+		/// - `key`: The key of the attribute.
+		/// - `value`: The value to which to set the attribute.
+		/// - `amount`: The deposit to reserve for the attribute.
+		///
+		/// Emits `AttributeSet` and `AttributeDepositChanged`, as well as
+		/// `AttributeDepositReassigned` if the attribute was set by another account.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(59)]
+		#[pallet::weight(T::WeightInfo::force_set_attribute())]
+		pub fn force_set_attribute_with_deposit(
+			origin: OriginFor<T>,
+			set_as: T::AccountId,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+			value: BoundedVec<u8, T::ValueLimit>,
+			amount: DepositBalanceOf<T, I>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_force_set_attribute_with_deposit(
+				set_as, collection, maybe_item, namespace, key, value, amount,
+			)
+		}
+
+		/// Allow an account to set attributes of any item of the collection.
+		///
+		/// Origin must be Signed and must be the owner of the `collection`.
+		///
+		/// The editor sets the attributes within its own `AttributeNamespace::Account` namespace
+		/// same as the delegates approved by `approve_item_attributes`, but with no per-item
+		/// approval needed. The editor always pays the deposits for such attributes itself.
+		///
+		/// - `collection`: The collection of the items.
+		/// - `editor`: The account to allow setting the attributes.
+		///
+		/// Emits `CollectionAttributeEditorAdded` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(60)]
+		#[pallet::weight(T::WeightInfo::add_collection_attribute_editor())]
+		pub fn add_collection_attribute_editor(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			editor: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let editor = T::Lookup::lookup(editor)?;
+			Self::do_add_collection_attribute_editor(origin, collection, editor)
+		}
+
+		/// Disallow an attribute editor to set attributes of the collection's items.
+		///
+		/// Origin must be Signed and must be the owner of the `collection`.
+		///
+		/// The attributes already set by the editor are kept, an item's owner can clear them
+		/// with `cancel_item_attributes_approval`.
+		///
+		/// - `collection`: The collection of the items.
+		/// - `editor`: The attribute editor to remove.
+		///
+		/// Emits `CollectionAttributeEditorRemoved` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(61)]
+		#[pallet::weight(T::WeightInfo::remove_collection_attribute_editor())]
+		pub fn remove_collection_attribute_editor(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			editor: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let editor = T::Lookup::lookup(editor)?;
+			Self::do_remove_collection_attribute_editor(origin, collection, editor)
+		}
+
+		/// Set an attribute for a collection or item storing its value compressed.
+		///
+		/// Follows the same rules as `set_attribute`, but the value is compressed with the
+		/// `T::AttributeCompressor` and the deposit is taken for the compressed value only. The
+		/// value is decompressed back when read through the `Inspect::attribute`.
+		///
+		/// - `collection`: The identifier of the collection whose item's metadata to set.
+		/// - `maybe_item`: The identifier of the item whose metadata to set.
+		/// - `namespace`: Attribute's namespace.
+		/// - `key`: The key of the attribute.
+		/// - `value`: The uncompressed value, up to `T::ValueLimit * T::MaxAttributeChunks` bytes.
+		///
+		/// Emits `AttributeSet` with the compressed value.
+		///
+		/// Weight: `O(V)` where `V` is the length of the `value`.
+		#[pallet::call_index(62)]
+		#[pallet::weight(
+			T::WeightInfo::set_attribute().saturating_mul(
+				(value.len() as u64 / T::ValueLimit::get().max(1) as u64).saturating_add(1)
+			)
+		)]
+		pub fn set_compressed_attribute(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+			value: Vec<u8>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_compressed_attribute(
+				origin.clone(),
+				collection,
+				maybe_item,
+				namespace,
+				key,
+				value,
+				origin,
+			)
+		}
 	}
 }
 
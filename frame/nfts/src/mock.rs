@@ -44,6 +44,7 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 		Nfts: pallet_nfts::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -91,8 +92,93 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<Self::AccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type WeightInfo = ();
+	type CallbackHandle = ();
+	type Extra = ();
+	type RemoveItemsLimit = ConstU32<5>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 parameter_types! {
 	pub storage Features: PalletFeatures = PalletFeatures::all_enabled();
+	pub storage DepositPerByte: u64 = 1;
+	pub storage AutoMoveItemAttributes: bool = false;
+	pub storage MinAttributeValueLen: u32 = 0;
+	pub storage ReservedKeyPrefixes: Vec<Vec<u8>> = Vec::new();
+	pub storage MaxAttributeWritesPerBlock: Option<u32> = None;
+	pub storage ItemAttributesApprovalsNearLimitThreshold: u32 = 0;
+	pub storage LostDeposits: Vec<(u32, AccountId, u64)> = Vec::new();
+	pub storage CollectionDepositAssets: Vec<(u32, u32)> = Vec::new();
+	pub DepositHolder: AccountId = AccountId::new([u8::MAX; 32]);
+}
+
+/// Takes the attribute deposits of the collections listed within the `CollectionDepositAssets`
+/// in the assets, the rest use the `Balances`.
+pub struct TestDepositAsset;
+
+impl DepositAsset<u32, u32> for TestDepositAsset {
+	fn deposit_asset(collection: &u32) -> Option<u32> {
+		CollectionDepositAssets::get()
+			.into_iter()
+			.find_map(|(c, asset)| (c == *collection).then_some(asset))
+	}
+}
+
+/// Records the lost attribute deposits into the `LostDeposits`.
+pub struct RecordLostDeposits;
+
+impl OnLostDeposit<Test> for RecordLostDeposits {
+	fn on_lost_deposit(collection: &u32, depositor: &AccountId, amount: u64) {
+		let mut lost = LostDeposits::get();
+		lost.push((*collection, depositor.clone(), amount));
+		LostDeposits::set(&lost);
+	}
+}
+
+/// Run-length encodes the attribute values as `(count, byte)` pairs.
+pub struct RleCompressor;
+
+impl AttributeCompressor for RleCompressor {
+	fn compress(value: &[u8]) -> Option<Vec<u8>> {
+		let mut compressed: Vec<u8> = Vec::new();
+		for &byte in value {
+			match compressed.as_mut_slice() {
+				[.., count, last] if *last == byte && *count < u8::MAX => *count += 1,
+				_ => compressed.extend([1, byte]),
+			}
+		}
+		Some(compressed)
+	}
+
+	fn decompress(value: &[u8], max_len: usize) -> Option<Vec<u8>> {
+		if value.len() % 2 != 0 {
+			return None
+		}
+		let mut decompressed = Vec::new();
+		for pair in value.chunks(2) {
+			decompressed.extend(std::iter::repeat(pair[1]).take(pair[0] as usize));
+			if decompressed.len() > max_len {
+				return None
+			}
+		}
+		Some(decompressed)
+	}
 }
 
 impl Config for Test {
@@ -103,19 +189,33 @@ impl Config for Test {
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<Self::AccountId>>;
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type Locker = ();
+	type NamespacePrecedence = DefaultNamespacePrecedence;
+	type AttributeDepositHandler = FungiblesDepositHandler<Assets, TestDepositAsset, DepositHolder>;
+	type OnLostDeposit = RecordLostDeposits;
 	type CollectionDeposit = ConstU64<2>;
 	type ItemDeposit = ConstU64<1>;
 	type MetadataDepositBase = ConstU64<1>;
 	type AttributeDepositBase = ConstU64<1>;
-	type DepositPerByte = ConstU64<1>;
+	type DepositPerByte = DepositPerByte;
 	type StringLimit = ConstU32<50>;
 	type KeyLimit = ConstU32<50>;
 	type ValueLimit = ConstU32<50>;
 	type ApprovalsLimit = ConstU32<10>;
 	type ItemAttributesApprovalsLimit = ConstU32<2>;
+	type ItemAttributesApprovalsNearLimitThreshold = ItemAttributesApprovalsNearLimitThreshold;
+	type CollectionAttributeEditorsLimit = ConstU32<2>;
 	type MaxTips = ConstU32<10>;
 	type MaxDeadlineDuration = ConstU64<10000>;
 	type MaxAttributesPerCall = ConstU32<2>;
+	type MaxAttributesPerItem = ConstU32<10>;
+	type MaxAttributeChunks = ConstU32<3>;
+	type AttributeCompressor = RleCompressor;
+	type MaxAttributeSchemas = ConstU32<2>;
+	type MaxAttributeValueOptions = ConstU32<3>;
+	type AutoMoveItemAttributes = AutoMoveItemAttributes;
+	type MinAttributeValueLen = MinAttributeValueLen;
+	type ReservedKeyPrefixes = ReservedKeyPrefixes;
+	type MaxAttributeWritesPerBlock = MaxAttributeWritesPerBlock;
 	type Features = Features;
 	/// Off-chain = signature On-chain - therefore no conversion needed.
 	/// It needs to be From<MultiSignature> for benchmarking.
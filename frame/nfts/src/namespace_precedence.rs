@@ -0,0 +1,142 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rules of resolving the attribute namespace when it's not specified explicitly.
+
+use crate::*;
+use frame_support::BoundedSlice;
+
+/// Determines which attribute namespace takes precedence when an item's attribute is read without
+/// specifying the namespace.
+pub trait NamespacePrecedence<T: Config<I>, I: 'static = ()> {
+	/// Returns the namespace the `key` attribute of the `item` should be read from.
+	fn namespace_precedence(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		key: &[u8],
+	) -> AttributeNamespace<T::AccountId>;
+
+	/// Returns the namespaces consulted when the `key` attribute of the `item` is read, in the
+	/// order they are consulted.
+	///
+	/// By default, that's the only namespace the attribute should be read from.
+	fn namespace_precedence_order(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		key: &[u8],
+	) -> Vec<AttributeNamespace<T::AccountId>> {
+		vec![Self::namespace_precedence(collection, item, key)]
+	}
+
+	/// Returns the namespace the `key` attribute of the `item` should be read from, or `None` if
+	/// the attribute doesn't exist in that namespace.
+	fn namespace_precedence_opt(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		key: &[u8],
+	) -> Option<AttributeNamespace<T::AccountId>> {
+		let namespace = Self::namespace_precedence(collection, item, key);
+		let key = BoundedSlice::<_, T::KeyLimit>::try_from(key).ok()?;
+		Attribute::<T, I>::contains_key((collection, Some(item), &namespace, key))
+			.then_some(namespace)
+	}
+
+	/// Returns the namespace the `key` attribute of the `item` is resolved from along with the
+	/// attribute's value, or `None` if the attribute doesn't exist in that namespace.
+	fn resolve_attribute(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		key: &[u8],
+	) -> Option<(AttributeNamespace<T::AccountId>, BoundedVec<u8, T::ValueLimit>)> {
+		let namespace = Self::namespace_precedence(collection, item, key);
+		let key = BoundedSlice::<_, T::KeyLimit>::try_from(key).ok()?;
+		Attribute::<T, I>::get((collection, Some(item), &namespace, key))
+			.map(|(value, _)| (namespace, value))
+	}
+}
+
+/// The default namespace precedence.
+///
+/// The `CollectionOwner` namespace is consulted first, then the `ItemOwner` and the `Pallet` ones.
+/// The expired attributes are skipped, so they don't shadow the live ones from the namespaces
+/// consulted later. Falls back to the `CollectionOwner` namespace when no live attribute was found
+/// in any of them.
+pub struct DefaultNamespacePrecedence;
+
+impl<T: Config<I>, I: 'static> NamespacePrecedence<T, I> for DefaultNamespacePrecedence {
+	fn namespace_precedence(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		key: &[u8],
+	) -> AttributeNamespace<T::AccountId> {
+		<Self as NamespacePrecedence<T, I>>::namespace_precedence_opt(collection, item, key)
+			.unwrap_or(AttributeNamespace::CollectionOwner)
+	}
+
+	fn namespace_precedence_order(
+		_collection: &T::CollectionId,
+		_item: &T::ItemId,
+		_key: &[u8],
+	) -> Vec<AttributeNamespace<T::AccountId>> {
+		vec![
+			AttributeNamespace::CollectionOwner,
+			AttributeNamespace::ItemOwner,
+			AttributeNamespace::Pallet,
+		]
+	}
+
+	fn namespace_precedence_opt(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		key: &[u8],
+	) -> Option<AttributeNamespace<T::AccountId>> {
+		let order =
+			<Self as NamespacePrecedence<T, I>>::namespace_precedence_order(collection, item, key);
+		let key = BoundedSlice::<_, T::KeyLimit>::try_from(key).ok()?;
+		order.into_iter().find(|namespace| {
+			Attribute::<T, I>::contains_key((collection, Some(item), namespace, key)) &&
+				!Pallet::<T, I>::has_expired(AttributeExpiryOf::<T, I>::get((
+					collection,
+					Some(item),
+					namespace,
+					key,
+				)))
+		})
+	}
+
+	fn resolve_attribute(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		key: &[u8],
+	) -> Option<(AttributeNamespace<T::AccountId>, BoundedVec<u8, T::ValueLimit>)> {
+		let order =
+			<Self as NamespacePrecedence<T, I>>::namespace_precedence_order(collection, item, key);
+		let key = BoundedSlice::<_, T::KeyLimit>::try_from(key).ok()?;
+		order.into_iter().find_map(|namespace| {
+			Attribute::<T, I>::get((collection, Some(item), &namespace, key))
+				.filter(|_| {
+					!Pallet::<T, I>::has_expired(AttributeExpiryOf::<T, I>::get((
+						collection,
+						Some(item),
+						&namespace,
+						key,
+					)))
+				})
+				.map(|(value, _)| (namespace, value))
+		})
+	}
+}
@@ -17,19 +17,25 @@
 
 //! Tests for Nfts pallet.
 
-use crate::{mock::*, Event, *};
+use crate::{
+	mock::*,
+	well_known_keys::{self, WellKnownKey},
+	Event, *,
+};
 use enumflags2::BitFlags;
 use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::Dispatchable,
+	error::BadOrigin,
 	traits::{
-		tokens::nonfungibles_v2::{Destroy, Mutate},
-		Currency, Get,
+		tokens::nonfungibles_v2::{Destroy, Inspect, Mutate},
+		Currency, Get, GetStorageVersion, OnInitialize, OnRuntimeUpgrade, ReservableCurrency,
+		StorageVersion,
 	},
 };
 use pallet_balances::Error as BalancesError;
 use sp_core::{bounded::BoundedVec, Pair};
-use sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner};
+use sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner, Perbill, Permill};
 use sp_std::prelude::*;
 
 type AccountIdOf<Test> = <Test as frame_system::Config>::AccountId;
@@ -616,6 +622,7 @@ fn set_collection_metadata_should_work() {
 		));
 		assert_eq!(Balances::free_balance(&account(1)), 9);
 		assert!(CollectionMetadataOf::<Test>::contains_key(0));
+		assert_eq!(Nfts::collection_metadata(0), Some(vec![0u8; 20]));
 
 		// Force origin works, too.
 		assert_ok!(Nfts::set_collection_metadata(RuntimeOrigin::root(), 0, bvec![0u8; 18]));
@@ -659,6 +666,15 @@ fn set_collection_metadata_should_work() {
 			Nfts::clear_collection_metadata(RuntimeOrigin::signed(account(1)), 0),
 			Error::<Test>::LockedCollectionMetadata
 		);
+		// the collection's attributes are locked separately
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
 
 		// Clear Metadata
 		assert_ok!(Nfts::set_collection_metadata(RuntimeOrigin::root(), 0, bvec![0u8; 15]));
@@ -676,6 +692,7 @@ fn set_collection_metadata_should_work() {
 		);
 		assert_ok!(Nfts::clear_collection_metadata(RuntimeOrigin::root(), 0));
 		assert!(!CollectionMetadataOf::<Test>::contains_key(0));
+		assert_eq!(Nfts::collection_metadata(0), None);
 	});
 }
 
@@ -701,6 +718,8 @@ fn set_item_metadata_should_work() {
 		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 20]));
 		assert_eq!(Balances::free_balance(&account(1)), 8);
 		assert!(ItemMetadataOf::<Test>::contains_key(0, 42));
+		assert_eq!(Nfts::item_metadata(0, 42), Some(vec![0u8; 20]));
+		assert_eq!(Nfts::item_info(0, 42).unwrap().metadata, Some(vec![0u8; 20]));
 
 		// Force origin works, too.
 		assert_ok!(Nfts::set_metadata(RuntimeOrigin::root(), 0, 42, bvec![0u8; 18]));
@@ -747,6 +766,7 @@ fn set_item_metadata_should_work() {
 		);
 		assert_ok!(Nfts::clear_metadata(RuntimeOrigin::root(), 0, 42));
 		assert!(!ItemMetadataOf::<Test>::contains_key(0, 42));
+		assert_eq!(Nfts::item_metadata(0, 42), None);
 	});
 }
 
@@ -796,6 +816,12 @@ fn set_collection_owner_attributes_should_work() {
 		);
 		assert_eq!(Balances::reserved_balance(account(1)), 10);
 		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 9);
+		assert_eq!(
+			Nfts::namespace_attribute_count(0, NamespaceDiscriminant::CollectionOwner),
+			Some(3)
+		);
+		assert_eq!(Nfts::namespace_attribute_count(0, NamespaceDiscriminant::ItemOwner), Some(0));
+		assert_eq!(Nfts::namespace_attribute_count(1, NamespaceDiscriminant::ItemOwner), None);
 
 		assert_ok!(Nfts::set_attribute(
 			RuntimeOrigin::signed(account(1)),
@@ -815,6 +841,30 @@ fn set_collection_owner_attributes_should_work() {
 		);
 		assert_eq!(Balances::reserved_balance(account(1)), 19);
 		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 18);
+		// the collection's attributes are not reported as the item's changes
+		assert_eq!(
+			Nfts::item_attribute_changes(0, 0),
+			vec![
+				AttributeChange {
+					namespace: AttributeNamespace::CollectionOwner,
+					key: vec![0],
+					value: Some(vec![0]),
+				},
+				AttributeChange {
+					namespace: AttributeNamespace::CollectionOwner,
+					key: vec![1],
+					value: Some(vec![0]),
+				},
+			]
+		);
+		assert!(events().contains(&Event::<Test>::AttributeSet {
+			collection: 0,
+			maybe_item: None,
+			key: bvec![0],
+			value: bvec![0; 10],
+			namespace: AttributeNamespace::CollectionOwner,
+			old_value: Some(bvec![0]),
+		}));
 
 		assert_ok!(Nfts::clear_attribute(
 			RuntimeOrigin::signed(account(1)),
@@ -831,223 +881,3783 @@ fn set_collection_owner_attributes_should_work() {
 			]
 		);
 		assert_eq!(Balances::reserved_balance(account(1)), 16);
+		assert!(events().contains(&Event::<Test>::AttributeCleared {
+			collection: 0,
+			maybe_item: Some(0),
+			key: bvec![1],
+			namespace: AttributeNamespace::CollectionOwner,
+			value: bvec![0],
+			deposit: 3,
+		}));
+		assert_eq!(
+			Nfts::namespace_attribute_count(0, NamespaceDiscriminant::CollectionOwner),
+			Some(2)
+		);
+		assert_eq!(
+			Nfts::items_attribute(0, vec![1, 0, 0], vec![0], AttributeNamespace::CollectionOwner),
+			vec![None, Some(vec![0]), Some(vec![0])]
+		);
+		assert_eq!(
+			Nfts::items_attribute(0, vec![0], vec![1], AttributeNamespace::CollectionOwner),
+			vec![None]
+		);
+		assert_eq!(
+			Nfts::item_attribute_changes(0, 0),
+			vec![AttributeChange {
+				namespace: AttributeNamespace::CollectionOwner,
+				key: vec![1],
+				value: None,
+			}]
+		);
+
+		// the attributes' deposits are moved along with the collection's ownership
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_ok!(Nfts::force_collection_owner(RuntimeOrigin::root(), 0, account(2)));
+		assert_eq!(Balances::reserved_balance(account(1)), 1);
+		assert_eq!(Balances::reserved_balance(account(2)), 15);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 15);
 
 		let w = Nfts::get_destroy_witness(&0).unwrap();
-		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(account(1)), 0, w));
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(account(2)), 0, w));
 		assert_eq!(attributes(0), vec![]);
 		assert_eq!(Balances::reserved_balance(account(1)), 0);
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
 	});
 }
 
 #[test]
-fn set_item_owner_attributes_should_work() {
+fn min_attribute_value_len_should_work() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&account(1), 100);
-		Balances::make_free_balance_be(&account(2), 100);
-		Balances::make_free_balance_be(&account(3), 100);
 
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
 			collection_config_with_all_settings_enabled()
 		));
-		assert_ok!(Nfts::force_mint(
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		// empty values are allowed by default
+		assert_ok!(Nfts::set_attribute(
 			RuntimeOrigin::signed(account(1)),
 			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![],
+		));
+
+		MinAttributeValueLen::set(&2);
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![1],
+				bvec![1],
+			),
+			Error::<Test>::ValueTooShort
+		);
+		assert_noop!(
+			Nfts::set_attributes(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![(bvec![1], bvec![1, 1]), (bvec![2], bvec![])],
+			),
+			Error::<Test>::ValueTooShort
+		);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
 			0,
-			account(2),
-			default_item_config()
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![1],
+			bvec![1, 1],
 		));
+	});
+}
+
+#[test]
+fn reserved_attribute_key_prefixes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		ReservedKeyPrefixes::set(&vec![b"sys:".to_vec()]);
 
-		// can't set for the collection
 		assert_noop!(
 			Nfts::set_attribute(
 				RuntimeOrigin::signed(account(2)),
 				0,
-				None,
+				Some(0),
 				AttributeNamespace::ItemOwner,
-				bvec![0],
-				bvec![0],
+				bvec![b's', b'y', b's', b':', 1],
+				bvec![1],
 			),
-			Error::<Test>::NoPermission,
+			Error::<Test>::ReservedKey
 		);
-		// can't set for the non-owned item
 		assert_noop!(
-			Nfts::set_attribute(
-				RuntimeOrigin::signed(account(1)),
+			Nfts::set_attributes(
+				RuntimeOrigin::signed(account(2)),
 				0,
 				Some(0),
 				AttributeNamespace::ItemOwner,
-				bvec![0],
-				bvec![0],
+				bvec![(bvec![1], bvec![1]), (bvec![b's', b'y', b's', b':'], bvec![1])],
 			),
-			Error::<Test>::NoPermission,
+			Error::<Test>::ReservedKey
 		);
-		assert_ok!(Nfts::set_attribute(
+		assert_ok!(Nfts::approve_item_attributes(
 			RuntimeOrigin::signed(account(2)),
 			0,
-			Some(0),
-			AttributeNamespace::ItemOwner,
-			bvec![0],
-			bvec![0],
+			0,
+			account(3)
 		));
+		Balances::make_free_balance_be(&account(3), 100);
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				Some(0),
+				AttributeNamespace::Account(account(3)),
+				bvec![b's', b'y', b's', b':'],
+				bvec![1],
+			),
+			Error::<Test>::ReservedKey
+		);
+
+		// the keys just looking alike are fine
 		assert_ok!(Nfts::set_attribute(
 			RuntimeOrigin::signed(account(2)),
 			0,
 			Some(0),
 			AttributeNamespace::ItemOwner,
+			bvec![b's', b'y', b's'],
 			bvec![1],
-			bvec![0],
 		));
+		// the collection's owner can write the reserved keys
 		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(2)),
+			RuntimeOrigin::signed(account(1)),
 			0,
 			Some(0),
-			AttributeNamespace::ItemOwner,
-			bvec![2],
-			bvec![0],
+			AttributeNamespace::CollectionOwner,
+			bvec![b's', b'y', b's', b':', 1],
+			bvec![1],
 		));
-		assert_eq!(
-			attributes(0),
-			vec![
-				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![1], bvec![0]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0]),
-			]
-		);
-		assert_eq!(Balances::reserved_balance(account(2)), 9);
+	});
+}
+
+#[test]
+fn attribute_writes_rate_limit_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		System::set_block_number(1);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		MaxAttributeWritesPerBlock::set(&Some(2));
 
-		// validate an attribute can be updated
 		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(2)),
+			RuntimeOrigin::signed(account(1)),
 			0,
 			Some(0),
-			AttributeNamespace::ItemOwner,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
 			bvec![0],
-			bvec![0; 10],
 		));
-		assert_eq!(
-			attributes(0),
-			vec![
-				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 10]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![1], bvec![0]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0]),
-			]
-		);
-		assert_eq!(Balances::reserved_balance(account(2)), 18);
-
-		// validate only item's owner (or the root) can remove an attribute
 		assert_noop!(
-			Nfts::clear_attribute(
+			Nfts::set_attributes(
 				RuntimeOrigin::signed(account(1)),
 				0,
 				Some(0),
 				AttributeNamespace::ItemOwner,
-				bvec![1],
+				bvec![(bvec![1], bvec![0]), (bvec![2], bvec![0])],
 			),
-			Error::<Test>::NoPermission,
-		);
-		assert_ok!(Nfts::clear_attribute(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			Some(0),
-			AttributeNamespace::ItemOwner,
-			bvec![1],
-		));
-		assert_eq!(
-			attributes(0),
-			vec![
-				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 10]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0])
-			]
-		);
-		assert_eq!(Balances::reserved_balance(account(2)), 15);
-
-		// transfer item
-		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 0, account(3)));
-
-		// validate the attribute are still here & the deposit belongs to the previous owner
-		assert_eq!(
-			attributes(0),
-			vec![
-				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 10]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0])
-			]
+			Error::<Test>::RateLimited
 		);
-		let key: BoundedVec<_, _> = bvec![0];
-		let (_, deposit) =
-			Attribute::<Test>::get((0, Some(0), AttributeNamespace::ItemOwner, &key)).unwrap();
-		assert_eq!(deposit.account, Some(account(2)));
-		assert_eq!(deposit.amount, 12);
-
-		// on attribute update the deposit should be returned to the previous owner
 		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(3)),
+			RuntimeOrigin::signed(account(1)),
 			0,
 			Some(0),
 			AttributeNamespace::ItemOwner,
+			bvec![1],
 			bvec![0],
-			bvec![0; 11],
 		));
-		let (_, deposit) =
-			Attribute::<Test>::get((0, Some(0), AttributeNamespace::ItemOwner, &key)).unwrap();
-		assert_eq!(deposit.account, Some(account(3)));
-		assert_eq!(deposit.amount, 13);
-		assert_eq!(Balances::reserved_balance(account(2)), 3);
-		assert_eq!(Balances::reserved_balance(account(3)), 13);
-
-		// validate attributes on item deletion
-		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(3)), 0, 0, None));
-		assert_eq!(
-			attributes(0),
-			vec![
-				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 11]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0])
-			]
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				None,
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::RateLimited
 		);
-		assert_ok!(Nfts::clear_attribute(
-			RuntimeOrigin::signed(account(3)),
+		// the forced writes are not limited
+		assert_ok!(Nfts::force_set_attribute(
+			RuntimeOrigin::root(),
+			None,
 			0,
 			Some(0),
-			AttributeNamespace::ItemOwner,
+			AttributeNamespace::Pallet,
+			bvec![0],
 			bvec![0],
+			false,
 		));
-		assert_ok!(Nfts::clear_attribute(
-			RuntimeOrigin::signed(account(2)),
+
+		// the limit is restored within the next block
+		System::set_block_number(2);
+		Nfts::on_initialize(2);
+		assert_eq!(AttributeWritesInBlock::<Test>::iter().count(), 0);
+		assert_ok!(Nfts::set_attributes(
+			RuntimeOrigin::signed(account(1)),
 			0,
 			Some(0),
 			AttributeNamespace::ItemOwner,
-			bvec![2],
+			bvec![(bvec![2], bvec![0]), (bvec![3], bvec![0])],
 		));
-		assert_eq!(Balances::reserved_balance(account(2)), 0);
-		assert_eq!(Balances::reserved_balance(account(3)), 0);
 	});
 }
 
 #[test]
-fn set_external_account_attributes_should_work() {
+fn empty_attribute_key_should_fail() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&account(1), 100);
-		Balances::make_free_balance_be(&account(2), 100);
 
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
 			collection_config_with_all_settings_enabled()
 		));
-		assert_ok!(Nfts::force_mint(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			0,
-			account(1),
-			default_item_config()
-		));
-		assert_ok!(Nfts::approve_item_attributes(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			0,
-			account(2)
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 0, bvec![0]));
+
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![],
+				bvec![1],
+			),
+			Error::<Test>::EmptyKey
+		);
+		assert_noop!(
+			Nfts::force_set_attribute(
+				RuntimeOrigin::root(),
+				None,
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![],
+				bvec![1],
+				false,
+			),
+			Error::<Test>::EmptyKey
+		);
+		assert_noop!(
+			Nfts::clear_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![],
+			),
+			Error::<Test>::EmptyKey
+		);
+		assert_eq!(attributes(0), vec![]);
+		assert_eq!(ItemMetadataOf::<Test>::get(0, 0).unwrap().data, bvec![0]);
+	});
+}
+
+#[test]
+fn simulate_set_attribute_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2),
+			default_item_config()
+		));
+		let reserved = Balances::reserved_balance(&account(2));
+		let events_count = System::events().len();
+
+		// nothing is stored nor reserved
+		assert_eq!(
+			Nfts::simulate_set_attribute(
+				account(2),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				vec![0],
+				vec![0; 10],
+			),
+			Ok(SetAttributeEffect {
+				deposit_delta: DepositDelta::Reserve(12),
+				will_create: true,
+				deposit_account: account(2),
+			})
+		);
+		assert!(!Nfts::has_attribute(0, 0, vec![0], AttributeNamespace::ItemOwner));
+		assert_eq!(Balances::reserved_balance(&account(2)), reserved);
+		assert_eq!(System::events().len(), events_count);
+
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0; 10],
+		));
+		assert_eq!(
+			Nfts::simulate_set_attribute(
+				account(2),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				vec![0],
+				vec![0; 2],
+			),
+			Ok(SetAttributeEffect {
+				deposit_delta: DepositDelta::Unreserve(8),
+				will_create: false,
+				deposit_account: account(2),
+			})
+		);
+		assert_eq!(
+			Nfts::simulate_set_attribute(
+				account(2),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				vec![0],
+				vec![0; 20],
+			)
+			.map(|effect| effect.deposit_delta),
+			Ok(DepositDelta::Reserve(10))
+		);
+
+		// the collection owner's deposit is resolved to the owner's account
+		assert_eq!(
+			Nfts::simulate_set_attribute(
+				account(1),
+				0,
+				None,
+				AttributeNamespace::CollectionOwner,
+				vec![0],
+				vec![0],
+			),
+			Ok(SetAttributeEffect {
+				deposit_delta: DepositDelta::Reserve(3),
+				will_create: true,
+				deposit_account: account(1),
+			})
+		);
+
+		// the same checks as in `set_attribute` are performed
+		assert_eq!(
+			Nfts::simulate_set_attribute(
+				account(1),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				vec![0],
+				vec![0],
+			),
+			Err(Error::<Test>::NoPermission.into())
+		);
+		assert_eq!(
+			Nfts::simulate_set_attribute(
+				account(2),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				vec![],
+				vec![0],
+			),
+			Err(Error::<Test>::EmptyKey.into())
+		);
+		assert_ok!(Nfts::lock_item_properties(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			false,
+			true
+		));
+		assert_eq!(
+			Nfts::simulate_set_attribute(
+				account(1),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				vec![0],
+				vec![0],
+			),
+			Err(Error::<Test>::LockedItemAttributes.into())
+		);
+	});
+}
+
+#[test]
+fn attribute_checked_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert!(matches!(
+			Nfts::attribute_checked(0, 0, vec![0], AttributeNamespace::CollectionOwner),
+			Err(Error::<Test>::UnknownCollection)
+		));
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert!(matches!(
+			Nfts::attribute_checked(0, 0, vec![0], AttributeNamespace::CollectionOwner),
+			Err(Error::<Test>::UnknownItem)
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		assert!(matches!(
+			Nfts::attribute_checked(0, 0, vec![0], AttributeNamespace::CollectionOwner),
+			Ok(None)
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![1],
+		));
+		assert!(matches!(
+			Nfts::attribute_checked(0, 0, vec![0], AttributeNamespace::CollectionOwner),
+			Ok(Some(value)) if value == vec![1]
+		));
+
+		// the attribute is readable by its storage key
+		let storage_key =
+			Nfts::item_attribute_storage_key(0, 0, vec![0], AttributeNamespace::CollectionOwner)
+				.unwrap();
+		let stored = frame_support::storage::unhashed::get::<(
+			BoundedVec<u8, <Test as Config>::ValueLimit>,
+			AttributeDepositOf<Test, ()>,
+		)>(&storage_key);
+		assert_eq!(stored.map(|(value, _)| value), Some(bvec![1]));
+		assert_eq!(
+			Nfts::item_attribute_storage_key(
+				0,
+				0,
+				vec![0; 51],
+				AttributeNamespace::CollectionOwner
+			),
+			None
+		);
+	});
+}
+
+#[test]
+fn lock_attribute_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		for key in [b"edition".to_vec(), b"name".to_vec()] {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				key.try_into().unwrap(),
+				bvec![1],
+			));
+		}
+
+		// only the collection's owner can lock the existing attribute
+		assert_noop!(
+			Nfts::lock_attribute(RuntimeOrigin::signed(account(2)), 0, Some(0), bvec![0]),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::lock_attribute(RuntimeOrigin::signed(account(1)), 0, Some(0), bvec![0]),
+			Error::<Test>::AttributeNotFound
+		);
+		let key: BoundedVec<_, _> = b"edition".to_vec().try_into().unwrap();
+		assert_ok!(Nfts::lock_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			key.clone()
+		));
+		assert!(events().contains(&Event::<Test>::AttributeKeyLocked {
+			collection: 0,
+			maybe_item: Some(0),
+			key: key.clone(),
+		}));
+
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				key.clone(),
+				bvec![2],
+			),
+			Error::<Test>::AttributeLocked
+		);
+		assert_noop!(
+			Nfts::clear_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				key.clone(),
+			),
+			Error::<Test>::AttributeLocked
+		);
+		assert_noop!(
+			Nfts::clear_attribute(
+				RuntimeOrigin::root(),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				key.clone(),
+			),
+			Error::<Test>::AttributeLocked
+		);
+
+		// the other attributes stay editable
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			b"name".to_vec().try_into().unwrap(),
+			bvec![2],
+		));
+		// as well as the same key within the other namespaces
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			key.clone(),
+			bvec![2],
+		));
+		assert_eq!(
+			Attribute::<Test>::get((0, Some(0), AttributeNamespace::CollectionOwner, &key))
+				.map(|(value, _)| value),
+			Some(bvec![1])
+		);
+	});
+}
+
+#[test]
+fn force_clear_attribute_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![1],
+			bvec![1],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![1],
+			bvec![1],
+		));
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 3);
+		assert_eq!(Balances::reserved_balance(account(2)), 3);
+		assert_eq!(Nfts::item_total_attribute_deposit(0, 0), 6);
+		assert_eq!(Nfts::item_total_attribute_deposit(0, 1), 0);
+
+		// lock both the key and the whole item's attributes
+		assert_ok!(Nfts::lock_attribute(RuntimeOrigin::signed(account(1)), 0, Some(0), bvec![1]));
+		assert_ok!(Nfts::lock_item_properties(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			false,
+			true
+		));
+		assert_noop!(
+			Nfts::clear_attribute(
+				RuntimeOrigin::root(),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![1],
+			),
+			Error::<Test>::AttributeLocked
+		);
+		assert_noop!(
+			Nfts::force_clear_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![1],
+			),
+			BadOrigin
+		);
+
+		let reserved_before = Balances::reserved_balance(account(1));
+		assert_ok!(Nfts::force_clear_attribute(
+			RuntimeOrigin::root(),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![1],
+		));
+		assert!(events().contains(&Event::<Test>::AttributeCleared {
+			collection: 0,
+			maybe_item: Some(0),
+			key: bvec![1],
+			namespace: AttributeNamespace::CollectionOwner,
+			value: bvec![1],
+			deposit: 3,
+		}));
+		assert_eq!(Balances::reserved_balance(account(1)), reserved_before - 3);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 0);
+		assert_eq!(Nfts::item_total_attribute_deposit(0, 0), 3);
+
+		// the deposit goes back to the account that paid it
+		assert_ok!(Nfts::force_clear_attribute(
+			RuntimeOrigin::root(),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![1],
+		));
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+		assert_eq!(attributes(0), vec![]);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 0);
+		assert_eq!(Nfts::item_total_attribute_deposit(0, 0), 0);
+		assert_noop!(
+			Nfts::force_clear_attribute(
+				RuntimeOrigin::root(),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![1],
+			),
+			Error::<Test>::AttributeNotFound
+		);
+	});
+}
+
+#[test]
+fn attribute_deposits_should_be_taken_in_the_collection_asset() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, account(1), true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(account(1)), 0, account(1), 100));
+		CollectionDepositAssets::set(&vec![(0, 0)]);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		for collection in [0, 1] {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				collection,
+				None,
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+				bvec![0],
+			));
+		}
+
+		// the first collection's deposit is paid in the asset and isn't a part of the
+		// `owner_deposit`
+		assert_eq!(Assets::balance(0, account(1)), 97);
+		assert_eq!(Assets::balance(0, DepositHolder::get()), 3);
+		assert_eq!(Balances::reserved_balance(account(1)), 3);
+		assert_eq!(
+			Attribute::<Test>::get((
+				0,
+				Option::<u32>::None,
+				AttributeNamespace::CollectionOwner,
+				bvec![0]
+			))
+			.map(|(_, deposit)| deposit),
+			Some(AttributeDeposit { account: Some(account(1)), amount: 3 })
+		);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 0);
+		assert_eq!(Collection::<Test>::get(1).unwrap().owner_deposit, 3);
+
+		// the new owner doesn't take over the deposit paid in the asset
+		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(2)), Some(0)));
+		assert_ok!(Nfts::transfer_ownership(RuntimeOrigin::signed(account(1)), 0, account(2)));
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+		));
+		assert_eq!(Assets::balance(0, account(1)), 100);
+		assert_eq!(Assets::balance(0, DepositHolder::get()), 0);
+		assert!(LostDeposits::get().is_empty());
+	});
+}
+
+#[test]
+fn lost_attribute_deposits_should_be_handled() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		for key in [1, 2] {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![key],
+				bvec![1],
+			));
+		}
+		assert_eq!(Balances::reserved_balance(account(2)), 6);
+
+		// nothing gets lost while the deposit can be returned
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![1],
+		));
+		assert_eq!(Balances::reserved_balance(account(2)), 3);
+		assert!(LostDeposits::get().is_empty());
+
+		// the depositor's reserve is gone, e.g. the account was reaped
+		let _ = Balances::slash_reserved(&account(2), 3);
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![2],
+		));
+		assert_eq!(LostDeposits::get(), vec![(0, account(2), 3)]);
+
+		// the delegate's refund only reports what was actually unreserved
+		Balances::make_free_balance_be(&account(3), 100);
+		assert_ok!(Nfts::approve_item_attributes(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			0,
+			account(3)
+		));
+		for key in [1, 2] {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				Some(0),
+				AttributeNamespace::Account(account(3)),
+				bvec![key],
+				bvec![1],
+			));
+		}
+		let _ = Balances::slash_reserved(&account(3), 2);
+		assert_ok!(Nfts::cancel_item_attributes_approval(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			0,
+			account(3),
+			CancelAttributesApprovalWitness { account_attributes: 2 },
+		));
+		assert!(events().contains(&Event::<Test>::ItemAttributesApprovalRemoved {
+			collection: 0,
+			item: 0,
+			delegate: account(3),
+			attributes_removed: 2,
+			deposit_refunded: 4,
+		}));
+		assert_eq!(LostDeposits::get(), vec![(0, account(2), 3), (0, account(3), 2)]);
+		assert_eq!(Balances::reserved_balance(account(3)), 0);
+		LostDeposits::set(&vec![]);
+
+		// clearing the whole namespace reports the lost deposits as well
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![3],
+			bvec![1],
+		));
+		let _ = Balances::slash_reserved(&account(2), 3);
+		assert_ok!(Nfts::clear_item_namespace(
+			RuntimeOrigin::root(),
+			0,
+			0,
+			AttributeNamespace::ItemOwner,
+			ClearItemNamespaceWitness { attributes: 1 },
+		));
+		assert_eq!(LostDeposits::get(), vec![(0, account(2), 3)]);
+
+		// so does the depositor's change when the new item's owner overwrites the attribute
+		Balances::make_free_balance_be(&account(4), 100);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![4],
+			bvec![1],
+		));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 0, account(4)));
+		let _ = Balances::slash_reserved(&account(2), 3);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(4)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![4],
+			bvec![2],
+		));
+		assert_eq!(LostDeposits::get(), vec![(0, account(2), 3), (0, account(2), 3)]);
+	});
+}
+
+#[test]
+fn attribute_schema_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+
+		let background: BoundedVec<_, _> = b"background".to_vec().try_into().unwrap();
+		let palette = ValueConstraint::OneOf(bvec![bvec![b'r'], bvec![b'g']]);
+		assert_noop!(
+			Nfts::set_attribute_schema(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				background.clone(),
+				Some(palette.clone())
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::set_attribute_schema(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			background.clone(),
+			Some(palette.clone())
+		));
+		assert!(events().contains(&Event::<Test>::AttributeSchemaSet {
+			collection: 0,
+			key: background.clone(),
+			constraint: Some(palette),
+		}));
+
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				background.clone(),
+				bvec![b'b'],
+			),
+			Error::<Test>::SchemaViolation
+		);
+		// the typed values are constrained too
+		assert_noop!(
+			Nfts::set_typed_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				background.clone(),
+				bvec![b'b'],
+				AttributeValueType::Utf8,
+			),
+			Error::<Test>::SchemaViolation
+		);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			background.clone(),
+			bvec![b'g'],
+		));
+		// the schema applies to the `CollectionOwner` namespace only
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			background.clone(),
+			bvec![b'b'],
+		));
+
+		// the number of schemas per collection is limited
+		assert_ok!(Nfts::set_attribute_schema(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			bvec![0],
+			Some(ValueConstraint::Numeric)
+		));
+		assert_noop!(
+			Nfts::set_attribute_schema(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				bvec![1],
+				Some(ValueConstraint::Utf8)
+			),
+			Error::<Test>::MaxAttributeSchemasReached
+		);
+		assert_ok!(Nfts::set_attribute_schema(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			background.clone(),
+			None
+		));
+		assert_ok!(Nfts::set_attribute_schema(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			bvec![1],
+			Some(ValueConstraint::MaxLen(2))
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			background,
+			bvec![b'b'],
+		));
+
+		let cases: [(ValueConstraintOf<Test, ()>, &[u8], bool); 8] = [
+			(ValueConstraint::Utf8, &b"ok"[..], true),
+			(ValueConstraint::Utf8, &[0xff][..], false),
+			(ValueConstraint::MaxLen(2), &[0, 0][..], true),
+			(ValueConstraint::MaxLen(2), &[0, 0, 0][..], false),
+			(ValueConstraint::Numeric, &b"42"[..], true),
+			(ValueConstraint::Numeric, &b"4a"[..], false),
+			(ValueConstraint::Numeric, &b""[..], false),
+			(ValueConstraint::OneOf(bvec![bvec![1]]), &[2][..], false),
+		];
+		for (constraint, value, satisfied) in cases {
+			assert_eq!(constraint.is_satisfied_by(value), satisfied);
+		}
+	});
+}
+
+#[test]
+fn clear_resolved_attribute_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![1],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![2],
+		));
+
+		// the `CollectionOwner` namespace takes precedence
+		assert_noop!(
+			Nfts::clear_resolved_attribute(RuntimeOrigin::signed(account(2)), 0, 0, bvec![0]),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::clear_resolved_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			bvec![0]
+		));
+		assert!(events().contains(&Event::<Test>::AttributeCleared {
+			collection: 0,
+			maybe_item: Some(0),
+			key: bvec![0],
+			namespace: AttributeNamespace::CollectionOwner,
+			value: bvec![1],
+			deposit: 3,
+		}));
+
+		assert_ok!(Nfts::clear_resolved_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			0,
+			bvec![0]
+		));
+		assert!(events().contains(&Event::<Test>::AttributeCleared {
+			collection: 0,
+			maybe_item: Some(0),
+			key: bvec![0],
+			namespace: AttributeNamespace::ItemOwner,
+			value: bvec![2],
+			deposit: 3,
+		}));
+		assert!(attributes(0).is_empty());
+
+		assert_noop!(
+			Nfts::clear_resolved_attribute(RuntimeOrigin::signed(account(2)), 0, 0, bvec![0]),
+			Error::<Test>::AttributeNotFound
+		);
+	});
+}
+
+#[test]
+fn set_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+
+		assert_ok!(Nfts::set_attributes(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![(bvec![0], bvec![0]), (bvec![1], bvec![0; 2])],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
+				(Some(0), AttributeNamespace::CollectionOwner, bvec![1], bvec![0; 2]),
+			]
+		);
+		// the item's deposit along with the deposits of both attributes
+		assert_eq!(Balances::reserved_balance(account(1)), 1 + 3 + 4);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 2);
+
+		// the unchanged attributes are skipped, same as by `set_attribute`
+		events();
+		assert_ok!(Nfts::set_attributes(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![(bvec![0], bvec![0]), (bvec![1], bvec![0])],
+		));
+		let events = events();
+		assert_eq!(
+			events
+				.iter()
+				.filter(|e| matches!(e, Event::<Test>::AttributeSet { .. }))
+				.count(),
+			1
+		);
+		assert!(events.contains(&Event::<Test>::AttributeDepositChanged {
+			collection: 0,
+			maybe_item: Some(0),
+			namespace: AttributeNamespace::CollectionOwner,
+			key: bvec![1],
+			depositor: account(1),
+			delta: 1,
+			reserved: false,
+			deposit: 3,
+		}));
+		assert_eq!(Balances::reserved_balance(account(1)), 1 + 3 + 3);
+
+		assert_noop!(
+			Nfts::set_attributes(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![(bvec![0], bvec![1])],
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn attribute_expiry_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		System::set_block_number(1);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+
+		assert_noop!(
+			Nfts::set_attribute_with_expiry(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				bvec![1],
+				1,
+			),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_ok!(Nfts::set_attribute_with_expiry(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![1],
+			3,
+		));
+		assert!(events().contains(&Event::<Test>::AttributeExpirySet {
+			collection: 0,
+			maybe_item: Some(0),
+			namespace: AttributeNamespace::ItemOwner,
+			key: bvec![0],
+			expiry: 3,
+		}));
+		assert_eq!(Balances::reserved_balance(&account(2)), 3);
+		assert!(matches!(
+			Nfts::attribute_checked(0, 0, vec![0], AttributeNamespace::ItemOwner),
+			Ok(Some(value)) if value == vec![1]
+		));
+		assert_noop!(
+			Nfts::reap_expired_attribute(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+			),
+			Error::<Test>::AttributeNotExpired
+		);
+
+		// the attribute is hidden once expired and anyone can reap it
+		System::set_block_number(3);
+		assert!(matches!(
+			Nfts::attribute_checked(0, 0, vec![0], AttributeNamespace::ItemOwner),
+			Ok(None)
+		));
+		assert_eq!(
+			Nfts::items_attribute(0, vec![0], vec![0], AttributeNamespace::ItemOwner),
+			vec![None]
+		);
+		assert_ok!(Nfts::reap_expired_attribute(
+			RuntimeOrigin::signed(account(3)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+		));
+		assert!(events().contains(&Event::<Test>::AttributeCleared {
+			collection: 0,
+			maybe_item: Some(0),
+			key: bvec![0],
+			namespace: AttributeNamespace::ItemOwner,
+			value: bvec![1],
+			deposit: 3,
+		}));
+		assert_eq!(Balances::reserved_balance(&account(2)), 0);
+		assert_eq!(AttributeExpiryOf::<Test>::iter().count(), 0);
+
+		// setting the attribute without an expiry removes the previous one
+		assert_ok!(Nfts::set_attribute_with_expiry(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![1],
+			5,
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![2],
+		));
+		System::set_block_number(10);
+		assert!(matches!(
+			Nfts::attribute_checked(0, 0, vec![0], AttributeNamespace::ItemOwner),
+			Ok(Some(value)) if value == vec![2]
+		));
+		assert_noop!(
+			Nfts::reap_expired_attribute(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+			),
+			Error::<Test>::AttributeNotExpired
+		);
+	});
+}
+
+#[test]
+fn expired_attributes_should_be_hidden_and_reapable_when_locked() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		assert_ok!(Nfts::set_attribute_with_expiry(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![1],
+			3,
+		));
+		assert_ok!(Nfts::lock_attribute(RuntimeOrigin::signed(account(1)), 0, Some(0), bvec![0]));
+		assert!(Nfts::has_attribute(0, 0, vec![0], AttributeNamespace::CollectionOwner));
+
+		System::set_block_number(3);
+		assert!(!Nfts::has_attribute(0, 0, vec![0], AttributeNamespace::CollectionOwner));
+		assert_eq!(Nfts::typed_attribute(0, 0, AttributeNamespace::CollectionOwner, vec![0]), None);
+		assert_eq!(Nfts::attribute_namespaces(0, 0, vec![0]), vec![]);
+		assert_eq!(
+			Nfts::item_attributes(0, 0, AttributeNamespace::CollectionOwner, None, 10),
+			(vec![], None)
+		);
+
+		// the key lock doesn't keep the expired value around
+		assert_noop!(
+			Nfts::clear_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+			),
+			Error::<Test>::AttributeLocked
+		);
+		let reserved = Balances::reserved_balance(&account(1));
+		assert_ok!(Nfts::reap_expired_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+		));
+		assert_eq!(attributes(0), vec![]);
+		assert_eq!(Balances::reserved_balance(&account(1)), reserved - 3);
+	});
+}
+
+#[test]
+fn set_attribute_if_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+
+		assert_noop!(
+			Nfts::set_attribute_if(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				Some(bvec![0]),
+				bvec![1],
+			),
+			Error::<Test>::PreconditionFailed
+		);
+		assert_ok!(Nfts::set_attribute_if(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			None,
+			bvec![1],
+		));
+		assert_eq!(Balances::reserved_balance(account(2)), 3);
+
+		// the attribute was changed in the meantime
+		assert_noop!(
+			Nfts::set_attribute_if(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				None,
+				bvec![2],
+			),
+			Error::<Test>::PreconditionFailed
+		);
+		assert_ok!(Nfts::set_attribute_if(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			Some(bvec![1]),
+			bvec![2, 2],
+		));
+		assert_eq!(
+			Nfts::attribute_with_deposit(&0, &0, &AttributeNamespace::ItemOwner, &[0]),
+			Some((vec![2, 2], Some(account(2)), 4))
+		);
+		assert_eq!(Balances::reserved_balance(account(2)), 4);
+
+		// the usual permission checks still apply
+		assert_noop!(
+			Nfts::set_attribute_if(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				Some(bvec![2, 2]),
+				bvec![3],
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn move_attribute_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(2), None));
+		for item in 0..2 {
+			let owner = if item == 0 { account(1) } else { account(2) };
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(owner),
+				0,
+				Some(item),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				bvec![1],
+			));
+		}
+		let reserved_before = Balances::reserved_balance(account(1));
+
+		// promote the item owner's value to the collection owner's one
+		assert_ok!(Nfts::move_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			AttributeNamespace::ItemOwner,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+		));
+		assert!(events().contains(&Event::<Test>::AttributeCleared {
+			collection: 0,
+			maybe_item: Some(0),
+			key: bvec![0],
+			namespace: AttributeNamespace::ItemOwner,
+			value: bvec![1],
+			deposit: 3,
+		}));
+		assert_eq!(
+			Nfts::attribute_with_deposit(&0, &0, &AttributeNamespace::CollectionOwner, &[0]),
+			Some((vec![1], None, 3))
+		);
+		assert_eq!(
+			Nfts::attribute_with_deposit(&0, &0, &AttributeNamespace::ItemOwner, &[0]),
+			None
+		);
+		assert_eq!(Balances::reserved_balance(account(1)), reserved_before);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 3);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 2);
+
+		// the other accounts' attributes can't be taken over
+		assert_noop!(
+			Nfts::move_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				1,
+				AttributeNamespace::ItemOwner,
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+			),
+			Error::<Test>::NoPermission
+		);
+		// as well as written into the namespaces the origin has no access to
+		assert_noop!(
+			Nfts::move_attribute(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				1,
+				AttributeNamespace::ItemOwner,
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::move_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				0,
+				AttributeNamespace::ItemOwner,
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+			),
+			Error::<Test>::AttributeNotFound
+		);
+	});
+}
+
+#[test]
+fn attribute_last_set_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		System::set_block_number(1);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		let namespace = AttributeNamespace::CollectionOwner;
+		assert_eq!(Nfts::attribute_last_set(0, 0, vec![0], namespace.clone()), None);
+
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			namespace.clone(),
+			bvec![0],
+			bvec![0],
+		));
+		assert_eq!(Nfts::attribute_last_set(0, 0, vec![0], namespace.clone()), Some(1));
+
+		System::set_block_number(5);
+		assert_ok!(Nfts::force_set_attribute(
+			RuntimeOrigin::root(),
+			None,
+			0,
+			Some(0),
+			namespace.clone(),
+			bvec![0],
+			bvec![1],
+			false,
+		));
+		assert_eq!(Nfts::attribute_last_set(0, 0, vec![0], namespace.clone()), Some(5));
+
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			namespace.clone(),
+			bvec![0],
+		));
+		assert_eq!(Nfts::attribute_last_set(0, 0, vec![0], namespace), None);
+		assert_eq!(AttributeLastSetOf::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn setting_unchanged_attribute_should_be_noop() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		MaxAttributeWritesPerBlock::set(&Some(1));
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		events();
+
+		// neither the storage is written nor the event is emitted, and the rate limit isn't hit
+		let storage_root = frame_support::storage_root(frame_support::StateVersion::V1);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_eq!(frame_support::storage_root(frame_support::StateVersion::V1), storage_root);
+		assert!(events().is_empty());
+
+		// the changed value is still written
+		System::set_block_number(2);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![1],
+		));
+		assert!(events().contains(&Event::<Test>::AttributeSet {
+			collection: 0,
+			maybe_item: Some(0),
+			key: bvec![0],
+			value: bvec![1],
+			namespace: AttributeNamespace::CollectionOwner,
+			old_value: Some(bvec![0]),
+		}));
+	});
+}
+
+#[test]
+fn set_item_owner_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		Balances::make_free_balance_be(&account(3), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2),
+			default_item_config()
+		));
+
+		// can't set for the collection
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				None,
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission,
+		);
+		// can't set for the non-owned item
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![1],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![2],
+			bvec![0],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![1], bvec![0]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0]),
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(2)), 9);
+
+		// validate an attribute can be updated
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0; 10],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 10]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![1], bvec![0]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0]),
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(2)), 18);
+
+		// validate only item's owner (or the root) can remove an attribute
+		assert_noop!(
+			Nfts::clear_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![1],
+			),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![1],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 10]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0])
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(2)), 15);
+
+		// transfer item
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 0, account(3)));
+
+		// validate the attribute are still here & the deposit belongs to the previous owner
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 10]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0])
+			]
+		);
+		let key: BoundedVec<_, _> = bvec![0];
+		let (_, deposit) =
+			Attribute::<Test>::get((0, Some(0), AttributeNamespace::ItemOwner, &key)).unwrap();
+		assert_eq!(deposit.account, Some(account(2)));
+		assert_eq!(deposit.amount, 12);
+
+		// on attribute update the deposit should be returned to the previous owner
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(3)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0; 11],
+		));
+		let (_, deposit) =
+			Attribute::<Test>::get((0, Some(0), AttributeNamespace::ItemOwner, &key)).unwrap();
+		assert_eq!(deposit.account, Some(account(3)));
+		assert_eq!(deposit.amount, 13);
+		assert_eq!(Balances::reserved_balance(account(2)), 3);
+		assert_eq!(Balances::reserved_balance(account(3)), 13);
+
+		// validate attributes on item deletion
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(3)), 0, 0, None));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 11]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0])
+			]
+		);
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(3)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+		));
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![2],
+		));
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+		assert_eq!(Balances::reserved_balance(account(3)), 0);
+	});
+}
+
+#[test]
+fn attribute_deposit_changed_event_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		Balances::make_free_balance_be(&account(3), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+
+		let deposit_changed = |depositor: u8, delta: u64, reserved: bool, deposit: u64| {
+			Event::<Test>::AttributeDepositChanged {
+				collection: 0,
+				maybe_item: Some(0),
+				namespace: AttributeNamespace::ItemOwner,
+				key: bvec![0],
+				depositor: account(depositor),
+				delta,
+				reserved,
+				deposit,
+			}
+		};
+
+		for (value, event) in [
+			(vec![0], deposit_changed(2, 3, true, 3)),
+			(vec![0; 5], deposit_changed(2, 4, true, 7)),
+			(vec![1], deposit_changed(2, 4, false, 3)),
+		] {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				value.try_into().unwrap(),
+			));
+			assert!(events().contains(&event));
+		}
+
+		// nothing is reported when the deposit stays the same
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![2],
+		));
+		assert!(!events()
+			.iter()
+			.any(|e| matches!(e, Event::<Test>::AttributeDepositChanged { .. })));
+
+		// the deposit is moved to the new owner
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 0, account(3)));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(3)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![3],
+		));
+		let events = events();
+		assert!(events.contains(&deposit_changed(2, 3, false, 0)));
+		assert!(events.contains(&deposit_changed(3, 3, true, 3)));
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+		assert_eq!(Balances::reserved_balance(account(3)), 3);
+	});
+}
+
+#[test]
+fn item_attributes_pagination_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		for key in 0..5u8 {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![key],
+				bvec![key],
+			));
+		}
+		assert_eq!(Nfts::collection_attributes_count(0), Some(5));
+		assert_eq!(Nfts::collection_attributes_count(1), None);
+
+		let mut collected = Vec::new();
+		let mut start_key = None;
+		loop {
+			let (page, next_key) =
+				Nfts::item_attributes(0, 0, AttributeNamespace::CollectionOwner, start_key, 2);
+			assert!(page.len() <= 2);
+			collected.extend(page);
+			if next_key.is_none() {
+				break
+			}
+			start_key = next_key;
+		}
+		collected.sort();
+		assert_eq!(collected, (0..5u8).map(|k| (vec![k], vec![k])).collect::<Vec<_>>());
+		let mut iterated: Vec<_> =
+			Nfts::attributes_iter(0, Some(0), AttributeNamespace::CollectionOwner).collect();
+		iterated.sort();
+		assert_eq!(iterated, collected);
+		assert_eq!(Nfts::attributes_iter(0, None, AttributeNamespace::CollectionOwner).count(), 0);
+
+		let (page, next_key) =
+			Nfts::item_attributes(0, 0, AttributeNamespace::CollectionOwner, None, 5);
+		assert_eq!(page.len(), 5);
+		assert_eq!(next_key, None);
+		let (page, next_key) = Nfts::item_attributes(0, 0, AttributeNamespace::ItemOwner, None, 5);
+		assert!(page.is_empty());
+		assert_eq!(next_key, None);
+
+		// the item's attributes don't belong to the collection
+		assert_eq!(Nfts::collection_attributes(0, None, 5), (Vec::new(), None));
+		for key in 0..3u8 {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				None,
+				AttributeNamespace::CollectionOwner,
+				bvec![key],
+				bvec![key],
+			));
+		}
+		let (mut page, next_key) = Nfts::collection_attributes(0, None, 2);
+		assert_eq!(page.len(), 2);
+		assert!(next_key.is_some());
+		let (rest, next_key) = Nfts::collection_attributes(0, next_key, 2);
+		assert_eq!(rest.len(), 1);
+		assert_eq!(next_key, None);
+		page.extend(rest);
+		page.sort();
+		assert_eq!(page, (0..3u8).map(|k| (vec![k], vec![k])).collect::<Vec<_>>());
+
+		for key in [b"trait:a".to_vec(), b"trait:b".to_vec(), b"name".to_vec()] {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				key.try_into().unwrap(),
+				bvec![0],
+			));
+		}
+		let (mut page, next_key) = Nfts::item_attributes_filtered(
+			0,
+			0,
+			AttributeNamespace::ItemOwner,
+			b"trait:".to_vec(),
+			None,
+			5,
+		);
+		page.sort();
+		assert_eq!(page, vec![(b"trait:a".to_vec(), vec![0]), (b"trait:b".to_vec(), vec![0])]);
+		assert_eq!(next_key, None);
+
+		let (page, next_key) = Nfts::item_attributes_filtered(
+			0,
+			0,
+			AttributeNamespace::ItemOwner,
+			b"trait:".to_vec(),
+			None,
+			1,
+		);
+		assert_eq!(page.len(), 1);
+		let (last_page, last_key) = Nfts::item_attributes_filtered(
+			0,
+			0,
+			AttributeNamespace::ItemOwner,
+			b"trait:".to_vec(),
+			next_key,
+			1,
+		);
+		assert_eq!(last_page.len(), 1);
+		assert_ne!(page, last_page);
+		assert_eq!(last_key, None);
+	});
+}
+
+#[test]
+fn resolve_attribute_namespace_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		assert_eq!(Nfts::resolve_attribute_namespace(0, 0, vec![0]), None);
+		assert!(!Nfts::item_has_any_attribute(0, 0));
+		assert_eq!(
+			Nfts::namespace_precedence_order(0, 0, vec![0]),
+			vec![
+				AttributeNamespace::CollectionOwner,
+				AttributeNamespace::ItemOwner,
+				AttributeNamespace::Pallet,
+			]
+		);
+
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_eq!(
+			Nfts::resolve_attribute_namespace(0, 0, vec![0]),
+			Some(AttributeNamespace::ItemOwner)
+		);
+		assert!(Nfts::has_attribute(0, 0, vec![0], AttributeNamespace::ItemOwner));
+		assert!(Nfts::item_has_any_attribute(0, 0));
+		assert!(!Nfts::item_has_any_attribute(0, 1));
+		assert!(!Nfts::has_attribute(0, 0, vec![0], AttributeNamespace::CollectionOwner));
+		assert!(!Nfts::has_attribute(0, 0, vec![1], AttributeNamespace::ItemOwner));
+
+		// the collection owner's value takes precedence
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![1],
+		));
+		assert_eq!(
+			Nfts::resolve_attribute_namespace(0, 0, vec![0]),
+			Some(AttributeNamespace::CollectionOwner)
+		);
+		assert_eq!(Nfts::resolve_attribute_namespace(0, 0, vec![1]), None);
+		assert_eq!(
+			Nfts::item_attribute_resolved(0, 0, vec![0]),
+			Some((vec![1], AttributeNamespace::CollectionOwner))
+		);
+		assert_eq!(Nfts::item_attribute_resolved(0, 0, vec![1]), None);
+		assert_eq!(
+			<DefaultNamespacePrecedence as NamespacePrecedence<Test>>::resolve_attribute(
+				&0,
+				&0,
+				&[0]
+			),
+			Some((AttributeNamespace::CollectionOwner, bvec![1]))
+		);
+		assert_eq!(Nfts::item_metadata_uri(0, 0, vec![0]), Some(vec![1]));
+
+		// the metadata URI falls back to the item's metadata
+		assert_eq!(Nfts::item_metadata_uri(0, 0, vec![1]), None);
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 0, bvec![2]));
+		assert_eq!(Nfts::item_metadata_uri(0, 0, vec![1]), Some(vec![2]));
+
+		assert_eq!(Nfts::well_known_attribute(0, 0, WellKnownKey::Name), None);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![b'n', b'a', b'm', b'e'],
+			bvec![3],
+		));
+		assert_eq!(WellKnownKey::Name.as_bytes(), well_known_keys::NAME);
+		assert_eq!(Nfts::well_known_attribute(0, 0, WellKnownKey::Name), Some(vec![3]));
+
+		let royalty_key = well_known_keys::ROYALTY.to_vec();
+		assert_eq!(Nfts::item_royalty(0, 0, royalty_key.clone()), None);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			BoundedVec::try_from(royalty_key.clone()).unwrap(),
+			BoundedVec::try_from((account(3), Permill::from_percent(5)).encode()).unwrap(),
+		));
+		assert_eq!(
+			Nfts::item_royalty(0, 0, royalty_key.clone()),
+			Some((account(3), Perbill::from_percent(5)))
+		);
+		// undecodable royalties are ignored
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			BoundedVec::try_from(royalty_key.clone()).unwrap(),
+			bvec![5],
+		));
+		assert_eq!(Nfts::item_royalty(0, 0, royalty_key), None);
+		// falls back to the collection owner's namespace when nothing is found
+		assert_eq!(
+			<DefaultNamespacePrecedence as NamespacePrecedence<Test>>::namespace_precedence(
+				&0,
+				&0,
+				&[1]
+			),
+			AttributeNamespace::CollectionOwner
+		);
+	});
+}
+
+#[test]
+fn item_attribute_any_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		Balances::make_free_balance_be(&account(3), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		assert_ok!(Nfts::approve_item_attributes(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			0,
+			account(3),
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(3)),
+			0,
+			Some(0),
+			AttributeNamespace::Account(account(3)),
+			bvec![0],
+			bvec![3],
+		));
+		assert_eq!(Nfts::item_attribute_any(0, 0, vec![1]), None);
+
+		// the delegated accounts' namespaces aren't consulted by the precedence
+		assert_eq!(Nfts::item_attribute_resolved(0, 0, vec![0]), None);
+		assert_eq!(
+			Nfts::item_attribute_any(0, 0, vec![0]),
+			Some((vec![3], AttributeNamespace::Account(account(3))))
+		);
+
+		// but the namespaces consulted by the precedence come first
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![2],
+		));
+		assert_eq!(
+			Nfts::item_attribute_any(0, 0, vec![0]),
+			Some((vec![2], AttributeNamespace::ItemOwner))
+		);
+	});
+}
+
+#[test]
+fn namespace_precedence_should_skip_expired_attributes() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		System::set_block_number(1);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		assert_ok!(Nfts::set_attribute_with_expiry(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![1],
+			3,
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![2],
+		));
+		assert_eq!(
+			<DefaultNamespacePrecedence as NamespacePrecedence<Test>>::resolve_attribute(
+				&0,
+				&0,
+				&[0]
+			),
+			Some((AttributeNamespace::CollectionOwner, bvec![1]))
+		);
+
+		// the expired collection owner's value no longer shadows the item owner's one
+		System::set_block_number(3);
+		assert_eq!(
+			<DefaultNamespacePrecedence as NamespacePrecedence<Test>>::resolve_attribute(
+				&0,
+				&0,
+				&[0]
+			),
+			Some((AttributeNamespace::ItemOwner, bvec![2]))
+		);
+		assert_eq!(
+			Nfts::resolve_attribute_namespace(0, 0, vec![0]),
+			Some(AttributeNamespace::ItemOwner)
+		);
+		assert_eq!(
+			Nfts::item_attribute_resolved(0, 0, vec![0]),
+			Some((vec![2], AttributeNamespace::ItemOwner))
+		);
+		assert_eq!(Nfts::item_metadata_uri(0, 0, vec![0]), Some(vec![2]));
+	});
+}
+
+#[test]
+fn set_typed_attribute_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+
+		assert_ok!(Nfts::set_typed_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![1],
+			AttributeValueType::Bool,
+		));
+		assert_eq!(
+			Nfts::typed_attribute(0, 0, AttributeNamespace::CollectionOwner, vec![0]),
+			Some((vec![1], AttributeValueType::Bool))
+		);
+
+		// a plain set resets the type
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![1],
+		));
+		assert_eq!(
+			Nfts::typed_attribute(0, 0, AttributeNamespace::CollectionOwner, vec![0]),
+			Some((vec![1], AttributeValueType::Raw))
+		);
+
+		assert_ok!(Nfts::set_typed_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0x7b, 0x7d],
+			AttributeValueType::Json,
+		));
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+		));
+		assert_eq!(Nfts::typed_attribute(0, 0, AttributeNamespace::CollectionOwner, vec![0]), None);
+		assert_eq!(AttributeValueTypeOf::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn recalculate_attribute_deposit_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0; 9],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0; 9],
+		));
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 11);
+		assert_eq!(Nfts::collection_owner_deposit(0), Some(11));
+		assert_eq!(Nfts::collection_owner_deposit(1), None);
+		assert_eq!(Balances::reserved_balance(account(2)), 11);
+		assert_eq!(
+			Nfts::compute_attribute_deposit(0, AttributeNamespace::CollectionOwner, 1, 9),
+			Some(11)
+		);
+		assert_eq!(
+			Nfts::compute_attribute_deposit(0, AttributeNamespace::ItemOwner, 1, 9),
+			Some(11)
+		);
+		assert_eq!(Nfts::compute_attribute_deposit(1, AttributeNamespace::ItemOwner, 1, 9), None);
+
+		// nothing to refund
+		assert_ok!(Nfts::recalculate_attribute_deposit(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+		));
+		assert_eq!(Balances::reserved_balance(account(2)), 11);
+
+		DepositPerByte::set(&0);
+
+		assert_noop!(
+			Nfts::recalculate_attribute_deposit(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::recalculate_attribute_deposit(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+		));
+		assert_eq!(Balances::reserved_balance(account(2)), 1);
+		assert!(events().contains(&Event::<Test>::AttributeDepositUpdated {
+			collection: 0,
+			maybe_item: Some(0),
+			namespace: AttributeNamespace::ItemOwner,
+			key: bvec![0],
+			old_deposit: 11,
+			new_deposit: 1,
+		}));
+
+		let reserved_before = Balances::reserved_balance(account(1));
+		assert_ok!(Nfts::recalculate_attribute_deposit(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+		));
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 1);
+		assert_eq!(Balances::reserved_balance(account(1)), reserved_before - 10);
+	});
+}
+
+#[test]
+fn clear_item_namespace_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		for key in 0..2 {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![key],
+				bvec![0],
+			));
+		}
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 3);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 6);
+
+		assert_noop!(
+			Nfts::clear_item_namespace(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				0,
+				AttributeNamespace::CollectionOwner,
+				ClearItemNamespaceWitness { attributes: 2 },
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::clear_item_namespace(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				0,
+				AttributeNamespace::CollectionOwner,
+				ClearItemNamespaceWitness { attributes: 1 },
+			),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(Nfts::clear_item_namespace(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			AttributeNamespace::CollectionOwner,
+			ClearItemNamespaceWitness { attributes: 2 },
+		));
+		assert!(events().contains(&Event::<Test>::ItemNamespaceCleared {
+			collection: 0,
+			item: 0,
+			namespace: AttributeNamespace::CollectionOwner,
+			attributes: 2,
+		}));
+		assert_eq!(
+			attributes(0),
+			vec![(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0])]
+		);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 1);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 0);
+		assert_eq!(Balances::reserved_balance(account(2)), 3);
+
+		assert_ok!(Nfts::clear_item_namespace(
+			RuntimeOrigin::root(),
+			0,
+			0,
+			AttributeNamespace::ItemOwner,
+			ClearItemNamespaceWitness { attributes: 1 },
+		));
+		assert_eq!(attributes(0), vec![]);
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+	});
+}
+
+#[test]
+fn transfer_item_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		Balances::make_free_balance_be(&account(3), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 0, account(3)));
+		assert_eq!(Balances::reserved_balance(account(2)), 3);
+
+		assert_noop!(
+			Nfts::transfer_item_attributes(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				0,
+				account(2),
+				TransferItemAttributesWitness { attributes: 1 },
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::transfer_item_attributes(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				0,
+				account(2),
+				TransferItemAttributesWitness { attributes: 0 },
+			),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(Nfts::transfer_item_attributes(
+			RuntimeOrigin::signed(account(3)),
+			0,
+			0,
+			account(2),
+			TransferItemAttributesWitness { attributes: 1 },
+		));
+		assert!(events().contains(&Event::<Test>::ItemAttributesTransferred {
+			collection: 0,
+			item: 0,
+			from: account(2),
+			to: account(3),
+			attributes: 1,
+		}));
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+		assert_eq!(Balances::reserved_balance(account(3)), 3);
+		assert_eq!(
+			Nfts::attribute_with_deposit(&0, &0, &AttributeNamespace::ItemOwner, &[0]),
+			Some((vec![0], Some(account(3)), 3))
+		);
+	});
+}
+
+#[test]
+fn auto_move_item_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		AutoMoveItemAttributes::set(&true);
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		Balances::make_free_balance_be(&account(3), 5);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![1],
+			bvec![1; 10],
+		));
+		assert_eq!(Balances::reserved_balance(account(2)), 15);
+
+		// the new owner can only afford the deposit of the first attribute
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 0, account(3)));
+		assert!(events().contains(&Event::<Test>::ItemAttributesTransferred {
+			collection: 0,
+			item: 0,
+			from: account(2),
+			to: account(3),
+			attributes: 1,
+		}));
+		assert!(events().contains(&Event::<Test>::ItemAttributeDepositNotMoved {
+			collection: 0,
+			item: 0,
+			key: bvec![1],
+			from: account(2),
+			to: account(3),
+		}));
+		assert_eq!(Balances::reserved_balance(account(2)), 12);
+		assert_eq!(Balances::reserved_balance(account(3)), 3);
+		assert_eq!(
+			Nfts::attribute_with_deposit(&0, &0, &AttributeNamespace::ItemOwner, &[0]),
+			Some((vec![0], Some(account(3)), 3))
+		);
+		assert_eq!(
+			Nfts::attribute_with_deposit(&0, &0, &AttributeNamespace::ItemOwner, &[1]),
+			Some((vec![1; 10], Some(account(2)), 12))
+		);
+	});
+}
+
+#[test]
+fn account_items_pagination_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		for item in 0..5 {
+			let owner = if item == 2 { account(2) } else { account(1) };
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, owner, None));
+		}
+
+		let mut collected = Vec::new();
+		let mut start = None;
+		loop {
+			let (page, next) = Nfts::account_items(account(1), 0, start, 2);
+			assert!(page.len() <= 2);
+			collected.extend(page);
+			match next {
+				Some(item) => start = Some(item),
+				None => break,
+			}
+		}
+		collected.sort();
+		assert_eq!(collected, vec![0, 1, 3, 4]);
+
+		assert_eq!(Nfts::account_items(account(2), 0, None, 10), (vec![2], None));
+		assert_eq!(Nfts::account_items(account(3), 0, None, 10), (vec![], None));
+	});
+}
+
+#[test]
+fn collection_namespace_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		for item in 0..5 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(1), None));
+			// the item `2` doesn't hold the attribute
+			if item != 2 {
+				assert_ok!(Nfts::set_attribute(
+					RuntimeOrigin::signed(account(1)),
+					0,
+					Some(item),
+					AttributeNamespace::ItemOwner,
+					bvec![0],
+					bvec![item as u8],
+				));
+			}
+		}
+		// the other namespaces and keys are not included
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(2),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![9],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(2),
+			AttributeNamespace::ItemOwner,
+			bvec![1],
+			bvec![9],
+		));
+
+		let mut collected = Vec::new();
+		let mut start = None;
+		loop {
+			let (page, next) = Nfts::collection_namespace_attributes(
+				0,
+				AttributeNamespace::ItemOwner,
+				vec![0],
+				start,
+				2,
+			);
+			assert!(page.len() <= 2);
+			collected.extend(page);
+			match next {
+				Some(item) => start = Some(item),
+				None => break,
+			}
+		}
+		collected.sort();
+		assert_eq!(collected, vec![(0, vec![0]), (1, vec![1]), (3, vec![3]), (4, vec![4])]);
+
+		assert_eq!(
+			Nfts::collection_namespace_attributes(0, AttributeNamespace::Pallet, vec![0], None, 10),
+			(vec![], None)
+		);
+		assert_eq!(
+			Nfts::collection_namespace_attributes(
+				1,
+				AttributeNamespace::ItemOwner,
+				vec![0],
+				None,
+				10
+			),
+			(vec![], None)
+		);
+	});
+}
+
+#[test]
+fn clear_orphaned_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0],
+		));
+
+		assert_noop!(
+			Nfts::clear_orphaned_attributes(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				0,
+				ClearOrphanedAttributesWitness { attributes: 2 },
+			),
+			Error::<Test>::ItemNotBurned
+		);
+
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(2)), 0, 0, None));
+		assert_eq!(attributes(0).len(), 2);
+
+		assert_noop!(
+			Nfts::clear_orphaned_attributes(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				0,
+				ClearOrphanedAttributesWitness { attributes: 1 },
+			),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(Nfts::clear_orphaned_attributes(
+			RuntimeOrigin::signed(account(3)),
+			0,
+			0,
+			ClearOrphanedAttributesWitness { attributes: 2 },
+		));
+		assert!(events().contains(&Event::<Test>::OrphanedAttributesCleared {
+			collection: 0,
+			item: 0,
+			attributes: 2,
+		}));
+		assert_eq!(attributes(0), vec![]);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 0);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 0);
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+	});
+}
+
+#[test]
+fn clear_collection_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		for maybe_item in [None, Some(0)] {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				maybe_item,
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+				bvec![0],
+			));
+		}
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 6);
+		assert_eq!(Balances::reserved_balance(account(2)), 3);
+
+		assert_noop!(
+			Nfts::clear_collection_attributes(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				AttributeCleanupWitness { attributes: 3 },
+			),
+			Error::<Test>::NoPermission
+		);
+		// the owner can't touch the attributes of the live items
+		assert_noop!(
+			Nfts::clear_collection_attributes(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				AttributeCleanupWitness { attributes: 3 },
+			),
+			Error::<Test>::CollectionNotEmpty
+		);
+
+		assert_ok!(Nfts::clear_collection_attributes(
+			RuntimeOrigin::root(),
+			0,
+			AttributeCleanupWitness { attributes: 2 },
+		));
+		assert!(events().contains(&Event::<Test>::CollectionAttributesCleared {
+			collection: 0,
+			attributes: 2,
+			remaining: 1,
+		}));
+		assert_eq!(attributes(0).len(), 1);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 1);
+
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(2)), 0, 0, None));
+		assert_ok!(Nfts::clear_collection_attributes(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			AttributeCleanupWitness { attributes: 2 },
+		));
+		assert!(events().contains(&Event::<Test>::CollectionAttributesCleared {
+			collection: 0,
+			attributes: 1,
+			remaining: 0,
+		}));
+		assert!(attributes(0).is_empty());
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 0);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 0);
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+		assert_eq!(Nfts::item_total_attribute_deposit(0, 0), 0);
+	});
+}
+
+#[test]
+fn max_attributes_per_item_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		let max_attributes: u8 = <Test as Config>::MaxAttributesPerItem::get() as u8;
+		for key in 0..max_attributes {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![key],
+				bvec![0],
+			));
+		}
+		assert_eq!(ItemAttributeCountOf::<Test>::get(0, 0), max_attributes as u32);
+
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![max_attributes],
+				bvec![0],
+			),
+			Error::<Test>::MaxAttributesReached
+		);
+		// existing attributes can still be updated
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![1],
+		));
+		// the collection's attributes are not limited
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+		));
+		assert_eq!(ItemAttributeCountOf::<Test>::get(0, 0), max_attributes as u32 - 1);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![max_attributes],
+			bvec![0],
+		));
+	});
+}
+
+#[test]
+fn force_set_attribute_can_preserve_deposit() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+
+		assert_ok!(Nfts::force_set_attribute(
+			RuntimeOrigin::root(),
+			Some(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![1],
+			true,
+		));
+		assert_eq!(
+			Nfts::attribute_with_deposit(&0, &0, &AttributeNamespace::ItemOwner, &[0]),
+			Some((vec![1], Some(account(2)), 3))
+		);
+		assert_eq!(Balances::reserved_balance(account(2)), 3);
+
+		// the deposit paid by the collection's owner
+		assert_ok!(Nfts::force_set_attribute(
+			RuntimeOrigin::root(),
+			None,
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![1],
+			true,
+		));
+		assert_eq!(
+			Nfts::attribute_with_deposit(&0, &0, &AttributeNamespace::CollectionOwner, &[0]),
+			Some((vec![1], None, 3))
+		);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 3);
+
+		// the dropped deposit is returned even if it was paid by `set_as`
+		assert_ok!(Nfts::force_set_attribute(
+			RuntimeOrigin::root(),
+			Some(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![2],
+			false,
+		));
+		assert_eq!(
+			Nfts::attribute_with_deposit(&0, &0, &AttributeNamespace::ItemOwner, &[0]),
+			Some((vec![2], Some(account(2)), 0))
+		);
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+
+		let reserved_before = Balances::reserved_balance(account(1));
+		assert_ok!(Nfts::force_set_attribute(
+			RuntimeOrigin::root(),
+			None,
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![2],
+			false,
+		));
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 0);
+		assert_eq!(Balances::reserved_balance(account(1)), reserved_before - 3);
+
+		// the previous depositor is reported when the attribute is set on behalf of another one
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![3],
+		));
+		assert_ok!(Nfts::force_set_attribute(
+			RuntimeOrigin::root(),
+			Some(account(3)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![4],
+			true,
+		));
+		assert!(events().contains(&Event::<Test>::AttributeDepositReassigned {
+			collection: 0,
+			maybe_item: Some(0),
+			namespace: AttributeNamespace::ItemOwner,
+			key: bvec![0],
+			from: Some(account(2)),
+			to: Some(account(3)),
+			deposit: 3,
+		}));
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+	});
+}
+
+#[test]
+fn force_set_attribute_with_deposit_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		Balances::make_free_balance_be(&account(3), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_eq!(Balances::reserved_balance(account(2)), 3);
+
+		assert_noop!(
+			Nfts::force_set_attribute_with_deposit(
+				RuntimeOrigin::signed(account(1)),
+				account(3),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				bvec![1],
+				10,
+			),
+			BadOrigin
+		);
+
+		// the previous depositor gets the deposit back
+		assert_ok!(Nfts::force_set_attribute_with_deposit(
+			RuntimeOrigin::root(),
+			account(3),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![1],
+			10,
+		));
+		assert_eq!(
+			Nfts::attribute_with_deposit(&0, &0, &AttributeNamespace::ItemOwner, &[0]),
+			Some((vec![1], Some(account(3)), 10))
+		);
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+		assert_eq!(Balances::reserved_balance(account(3)), 10);
+
+		// the old deposit is returned even if it was paid by the same account
+		assert_ok!(Nfts::force_set_attribute_with_deposit(
+			RuntimeOrigin::root(),
+			account(3),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![2],
+			5,
+		));
+		assert_eq!(Balances::reserved_balance(account(3)), 5);
+		assert!(events().contains(&Event::<Test>::AttributeDepositChanged {
+			collection: 0,
+			maybe_item: Some(0),
+			namespace: AttributeNamespace::ItemOwner,
+			key: bvec![0],
+			depositor: account(3),
+			delta: 5,
+			reserved: false,
+			deposit: 5,
+		}));
+
+		assert_noop!(
+			Nfts::force_set_attribute_with_deposit(
+				RuntimeOrigin::root(),
+				account(3),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				bvec![3],
+				1000,
+			),
+			BalancesError::<Test, _>::InsufficientBalance
+		);
+		assert_eq!(Nfts::item_total_attribute_deposit(0, 0), 5);
+
+		// the collection owner's deposit is accounted within the `owner_deposit`
+		assert_ok!(Nfts::force_set_attribute_with_deposit(
+			RuntimeOrigin::root(),
+			account(1),
+			0,
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+			4,
+		));
+		assert_eq!(
+			Attribute::<Test>::get((
+				0,
+				Option::<u32>::None,
+				AttributeNamespace::CollectionOwner,
+				bvec![0]
+			))
+			.map(|(_, deposit)| deposit),
+			Some(AttributeDeposit { account: None, amount: 4 })
+		);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 4);
+		assert_eq!(Balances::reserved_balance(account(1)), 4 + 1);
+	});
+}
+
+#[test]
+fn collection_attribute_with_deposit_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		assert_eq!(Nfts::collection_attribute_with_deposit(&0, &[0]), None);
+
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0, 0],
+		));
+		assert_eq!(Nfts::collection_attribute_with_deposit(&0, &[0]), Some((vec![0, 0], 4)));
+
+		// the item's attributes aren't taken into account
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![1],
+			bvec![0],
+		));
+		assert_eq!(Nfts::collection_attribute_with_deposit(&0, &[1]), None);
+
+		// the deposit paid by another account isn't a part of the owner's deposit
+		assert_ok!(Nfts::force_set_attribute_with_deposit(
+			RuntimeOrigin::root(),
+			account(2),
+			0,
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![2],
+			bvec![0],
+			10,
+		));
+		assert_eq!(Nfts::collection_attribute_with_deposit(&0, &[2]), Some((vec![0], 0)));
+	});
+}
+
+#[test]
+fn sponsored_delegate_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(1),
+			default_item_config()
+		));
+		assert_ok!(Nfts::approve_item_attributes(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2)
+		));
+
+		assert_noop!(
+			Nfts::set_delegate_attributes_sponsorship(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				0,
+				true
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::set_delegate_attributes_sponsorship(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			true
+		));
+		assert!(ItemConfigOf::<Test>::get(0, 0)
+			.unwrap()
+			.has_disabled_setting(ItemSetting::DelegatesPayDeposit));
+		assert!(events().contains(&Event::<Test>::DelegateAttributesSponsorshipSet {
+			collection: 0,
+			item: 0,
+			sponsor: true,
+		}));
+
+		// the item's owner pays the deposit for the delegate's attribute
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::Account(account(2)),
+			bvec![0],
+			bvec![0],
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), 3);
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+		assert!(events().contains(&Event::<Test>::AttributeDepositSponsored {
+			collection: 0,
+			item: 0,
+			key: bvec![0],
+			delegate: account(2),
+			sponsor: account(1),
+		}));
+
+		// the already paid deposits stay with the sponsor
+		assert_ok!(Nfts::set_delegate_attributes_sponsorship(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			false
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::Account(account(2)),
+			bvec![1],
+			bvec![0],
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), 3);
+		assert_eq!(Balances::reserved_balance(account(2)), 3);
+		assert_eq!(Nfts::namespace_attribute_count(0, NamespaceDiscriminant::Account), Some(2));
+
+		// every depositor gets refunded once the approval is cancelled
+		assert_ok!(Nfts::cancel_item_attributes_approval(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2),
+			CancelAttributesApprovalWitness { account_attributes: 2 },
+		));
+		// only the deposit paid by the delegate itself is reported
+		assert!(events().contains(&Event::<Test>::ItemAttributesApprovalRemoved {
+			collection: 0,
+			item: 0,
+			delegate: account(2),
+			attributes_removed: 2,
+			deposit_refunded: 3,
+		}));
+		assert_eq!(attributes(0), vec![]);
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+		assert_eq!(Nfts::namespace_attribute_count(0, NamespaceDiscriminant::Account), Some(0));
+	});
+}
+
+#[test]
+fn collection_attributes_counter_should_stay_in_sync() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		let attributes_count = || Collection::<Test>::get(0).unwrap().attributes;
+
+		// force-set a new attribute, then overwrite it both forcefully and normally
+		assert_ok!(Nfts::force_set_attribute(
+			RuntimeOrigin::root(),
+			None,
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+			false,
+		));
+		assert_eq!(attributes_count(), 1);
+		assert_ok!(Nfts::force_set_attribute(
+			RuntimeOrigin::root(),
+			Some(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![1],
+			false,
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![2],
+		));
+		assert_eq!(attributes_count(), 1);
+
+		// a double clear doesn't affect the counter
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+		));
+		assert_eq!(attributes_count(), 0);
+		assert_noop!(
+			Nfts::clear_attribute(
+				RuntimeOrigin::root(),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+			),
+			Error::<Test>::AttributeNotFound
+		);
+		assert_eq!(attributes_count(), 0);
+
+		// set normally, clear forcefully
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::force_set_attribute(
+			RuntimeOrigin::root(),
+			None,
+			0,
+			Some(0),
+			AttributeNamespace::Pallet,
+			bvec![0],
+			bvec![0],
+			false,
+		));
+		assert_eq!(attributes_count(), 2);
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::root(),
+			0,
+			Some(0),
+			AttributeNamespace::Pallet,
+			bvec![0],
+		));
+		assert_eq!(attributes_count(), 1);
+
+		// the delegate's attributes are accounted when the approval is cancelled
+		assert_ok!(Nfts::approve_item_attributes(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2)
+		));
+		assert_ok!(Nfts::force_set_attribute(
+			RuntimeOrigin::root(),
+			Some(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::Account(account(2)),
+			bvec![0],
+			bvec![0],
+			false,
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::Account(account(2)),
+			bvec![1],
+			bvec![0],
+		));
+		assert_eq!(attributes_count(), 3);
+		assert_ok!(Nfts::cancel_item_attributes_approval_exact(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2),
+		));
+		assert_eq!(attributes_count(), 1);
+		assert_eq!(Nfts::namespace_attribute_count(0, NamespaceDiscriminant::Account), Some(0));
+
+		let w = Nfts::get_destroy_witness(&0).unwrap();
+		assert_eq!(w.attributes, 1);
+	});
+}
+
+#[test]
+fn large_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+
+		assert_noop!(
+			Nfts::do_set_large_attribute(
+				account(1),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				vec![],
+				account(1),
+			),
+			Error::<Test>::InvalidChunksNumber
+		);
+		assert_noop!(
+			Nfts::do_set_large_attribute(
+				account(1),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				vec![bvec![0]; 4],
+				account(1),
+			),
+			Error::<Test>::InvalidChunksNumber
+		);
+
+		assert_ok!(Nfts::set_large_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![bvec![1; 2], bvec![2; 2], bvec![3]],
+		));
+		assert_eq!(
+			Nfts::large_attribute(0, 0, AttributeNamespace::ItemOwner, vec![0]),
+			Some(vec![1, 1, 2, 2, 3])
+		);
+		// every chunk and the manifest require a deposit, on top of the item's deposit
+		assert_eq!(Balances::reserved_balance(account(1)), 1 + 8 + 8 + 7 + 6);
+		assert_eq!(Nfts::large_attribute(0, 0, AttributeNamespace::CollectionOwner, vec![0]), None);
+
+		// the chunks left from the previous value are cleared
+		assert_ok!(Nfts::set_large_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![bvec![4; 2]],
+		));
+		assert_eq!(
+			Nfts::large_attribute(0, 0, AttributeNamespace::ItemOwner, vec![0]),
+			Some(vec![4, 4])
+		);
+		assert_eq!(ItemAttributeCountOf::<Test>::get(0, 0), 2);
+		assert_eq!(Balances::reserved_balance(account(1)), 1 + 8 + 6);
+
+		// the manifest can't be cleared without its chunks
+		assert_noop!(
+			Nfts::clear_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+			),
+			Error::<Test>::LargeAttributeNotClearable
+		);
+		// nor can it be forged to point at more chunks than allowed
+		for value_type in [AttributeValueType::ChunkManifest, AttributeValueType::Compressed] {
+			assert_noop!(
+				Nfts::set_typed_attribute(
+					RuntimeOrigin::signed(account(1)),
+					0,
+					Some(0),
+					AttributeNamespace::ItemOwner,
+					bvec![1],
+					u32::MAX.encode().try_into().unwrap(),
+					value_type,
+				),
+				Error::<Test>::ReservedValueType
+			);
+		}
+
+		assert_ok!(Nfts::clear_large_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+		));
+		assert_eq!(attributes(0), vec![]);
+		assert_eq!(Balances::reserved_balance(account(1)), 1);
+		assert_noop!(
+			Nfts::clear_large_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+			),
+			Error::<Test>::AttributeNotFound
+		);
+	});
+}
+
+#[test]
+fn compressed_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		let reserved = Balances::reserved_balance(account(1));
+
+		// the value exceeding the `ValueLimit` is stored compressed
+		assert_ok!(Nfts::set_compressed_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			vec![7; 100],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![100, 7])]
+		);
+		assert_eq!(Balances::reserved_balance(account(1)), reserved + 4);
+		assert_eq!(
+			Nfts::attribute(&0, &0, &AttributeNamespace::ItemOwner, &[0]),
+			Some(vec![7; 100])
+		);
+		// the queries return the decompressed value as well
+		assert_eq!(
+			Nfts::item_attributes(0, 0, AttributeNamespace::ItemOwner, None, 10),
+			(vec![(vec![0], vec![7; 100])], None)
+		);
+		assert_eq!(
+			Nfts::items_attribute(0, vec![0], vec![0], AttributeNamespace::ItemOwner),
+			vec![Some(vec![7; 100])]
+		);
+		assert_eq!(
+			Nfts::attribute_namespaces(0, 0, vec![0]),
+			vec![(AttributeNamespace::ItemOwner, vec![7; 100])]
+		);
+		assert_eq!(
+			Nfts::item_attribute_resolved(0, 0, vec![0]),
+			Some((vec![7; 100], AttributeNamespace::ItemOwner))
+		);
+		assert_eq!(Nfts::item_metadata_uri(0, 0, vec![0]), Some(vec![7; 100]));
+		assert_eq!(
+			Nfts::typed_attribute(0, 0, AttributeNamespace::ItemOwner, vec![0]),
+			Some((vec![7; 100], AttributeValueType::Compressed))
+		);
+		assert_eq!(
+			Nfts::attribute_with_deposit(&0, &0, &AttributeNamespace::ItemOwner, &[0]),
+			Some((vec![7; 100], Some(account(1)), 4))
+		);
+		assert_eq!(
+			Nfts::collection_namespace_attributes(
+				0,
+				AttributeNamespace::ItemOwner,
+				vec![0],
+				None,
+				10
+			),
+			(vec![(0, vec![7; 100])], None)
+		);
+
+		assert_ok!(Nfts::set_compressed_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			vec![1; 60],
+		));
+		assert_eq!(Nfts::collection_attribute(&0, &[0]), Some(vec![1; 60]));
+		assert_eq!(Nfts::collection_attributes(0, None, 10), (vec![(vec![0], vec![1; 60])], None));
+		assert_eq!(
+			Nfts::collection_attribute_with_deposit(&0, &[0]).map(|(value, _)| value),
+			Some(vec![1; 60])
+		);
+
+		// both the uncompressed and the compressed values are limited
+		assert_noop!(
+			Nfts::set_compressed_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![1],
+				vec![0; 151],
+			),
+			Error::<Test>::CompressedValueTooLong
+		);
+		assert_noop!(
+			Nfts::set_compressed_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![1],
+				(0..26).collect(),
+			),
+			Error::<Test>::CompressedValueTooLong
+		);
+
+		// the regular attributes are returned as is
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![100, 7],
+		));
+		assert_eq!(
+			Nfts::attribute(&0, &0, &AttributeNamespace::ItemOwner, &[0]),
+			Some(vec![100, 7])
+		);
+	});
+}
+
+#[test]
+fn set_external_account_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(1),
+			default_item_config()
+		));
+		assert_ok!(Nfts::approve_item_attributes(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2)
 		));
+		assert_eq!(Nfts::item_attribute_approvals_count(0, 0), 1);
+		assert_eq!(Nfts::item_attribute_approvals(0, 0), vec![account(2)]);
+		assert_eq!(Nfts::item_attribute_delegates(0, 0), vec![(account(2), 0)]);
+		assert!(Nfts::is_item_attribute_delegate(0, 0, account(2)));
+		assert!(!Nfts::is_item_attribute_delegate(0, 0, account(3)));
+		assert!(!Nfts::is_item_attribute_delegate(0, 1, account(2)));
+		assert_eq!(
+			Nfts::can_set_attribute(
+				&account(2),
+				&AttributeNamespace::Account(account(2)),
+				&0,
+				&Some(0)
+			),
+			Ok(true)
+		);
+		assert_eq!(
+			Nfts::can_set_attribute(
+				&account(2),
+				&AttributeNamespace::Account(account(1)),
+				&0,
+				&Some(0)
+			),
+			Ok(false)
+		);
+		assert_eq!(
+			Nfts::can_set_attribute(&account(2), &AttributeNamespace::ItemOwner, &1, &Some(0)),
+			Err(Error::<Test>::UnknownCollection.into())
+		);
 
 		assert_noop!(
 			Nfts::set_attribute(
@@ -1084,15 +4694,45 @@ fn set_external_account_attributes_should_work() {
 			]
 		);
 		assert_eq!(Balances::reserved_balance(account(2)), 6);
+		assert_eq!(Nfts::item_attribute_delegates(0, 0), vec![(account(2), 2)]);
+		assert_eq!(
+			Nfts::attribute_with_deposit(&0, &0, &AttributeNamespace::Account(account(2)), &[1]),
+			Some((vec![0], Some(account(2)), 3))
+		);
+
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![1],
+		));
+		assert_eq!(
+			Nfts::attribute_namespaces(0, 0, vec![0]),
+			vec![
+				(AttributeNamespace::ItemOwner, vec![1]),
+				(AttributeNamespace::Account(account(2)), vec![0]),
+			]
+		);
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+		));
 
-		// remove permission to set attributes
+		// remove permission to set attributes, the witness isn't validated
+		assert_eq!(ItemAttributesDelegateCount::<Test>::get((0, 0, account(2))), Some(2));
 		assert_ok!(Nfts::cancel_item_attributes_approval(
 			RuntimeOrigin::signed(account(1)),
 			0,
 			0,
 			account(2),
-			CancelAttributesApprovalWitness { account_attributes: 2 },
+			CancelAttributesApprovalWitness { account_attributes: 1 },
 		));
+		assert_eq!(ItemAttributesDelegateCount::<Test>::get((0, 0, account(2))), None);
 		assert_eq!(attributes(0), vec![]);
 		assert_eq!(Balances::reserved_balance(account(2)), 0);
 		assert_noop!(
@@ -1109,6 +4749,91 @@ fn set_external_account_attributes_should_work() {
 	});
 }
 
+#[test]
+fn migration_v1_should_count_delegated_attributes_lazily() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(1),
+			default_item_config()
+		));
+		assert_ok!(Nfts::approve_item_attributes(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2)
+		));
+		for key in 0..2 {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				Some(0),
+				AttributeNamespace::Account(account(2)),
+				bvec![key],
+				bvec![0],
+			));
+		}
+
+		// the attributes were set before the counter was introduced
+		ItemAttributesDelegateCount::<Test>::remove((0, 0, account(2)));
+		StorageVersion::new(0).put::<Nfts>();
+
+		migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Nfts::on_chain_storage_version(), 1);
+		assert_eq!(ItemAttributesDelegateCount::<Test>::get((0, 0, account(2))), None);
+		assert_eq!(Nfts::item_attributes_delegate_count(0, 0, account(2)), 2);
+		assert_eq!(
+			Nfts::item_attribute_any(0, 0, vec![1]),
+			Some((vec![0], AttributeNamespace::Account(account(2))))
+		);
+
+		// the counter picks up the attributes set before once it's updated
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::Account(account(2)),
+			bvec![2],
+			bvec![0],
+		));
+		assert_eq!(ItemAttributesDelegateCount::<Test>::get((0, 0, account(2))), Some(3));
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::Account(account(2)),
+			bvec![2],
+		));
+
+		assert_ok!(Nfts::cancel_item_attributes_approval_exact(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2),
+		));
+		assert!(events().contains(&Event::<Test>::ItemAttributesApprovalRemoved {
+			collection: 0,
+			item: 0,
+			delegate: account(2),
+			attributes_removed: 2,
+			deposit_refunded: 6,
+		}));
+		assert_eq!(attributes(0), vec![]);
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+	});
+}
+
 #[test]
 fn validate_deposit_required_setting() {
 	new_test_ext().execute_with(|| {
@@ -1264,6 +4989,8 @@ fn set_attribute_should_respect_lock() {
 			),
 			e
 		);
+		// the collection's metadata is locked separately
+		assert_ok!(Nfts::set_collection_metadata(RuntimeOrigin::signed(account(1)), 0, bvec![0]));
 		assert_ok!(Nfts::set_attribute(
 			RuntimeOrigin::signed(account(1)),
 			0,
@@ -1280,6 +5007,8 @@ fn set_attribute_should_respect_lock() {
 			false,
 			true
 		));
+		assert!(Nfts::item_attributes_locked(0, 0));
+		assert!(!Nfts::item_attributes_locked(0, 1));
 		let e = Error::<Test>::LockedItemAttributes;
 		assert_noop!(
 			Nfts::set_attribute(
@@ -1300,6 +5029,82 @@ fn set_attribute_should_respect_lock() {
 			bvec![0],
 			bvec![1],
 		));
+
+		// the collection's lock is respected even when the item's config is gone
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 1, None));
+		assert!(!ItemConfigOf::<Test>::contains_key(0, 1));
+		assert!(Nfts::item_attributes_locked(0, 1));
+		assert_noop!(
+			Nfts::clear_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(1),
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+			),
+			Error::<Test>::CannotInferLockState
+		);
+	});
+}
+
+#[test]
+fn setting_attributes_of_unknown_item_should_fail() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::add_collection_attribute_editor(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			account(2)
+		));
+
+		// the config of a frozen item outlives the item
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		assert_ok!(Nfts::lock_item_properties(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			true,
+			false
+		));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 0, None));
+		assert!(ItemConfigOf::<Test>::contains_key(0, 0));
+
+		for item in [0, 1] {
+			for (origin, namespace) in [
+				(account(1), AttributeNamespace::Pallet),
+				(account(1), AttributeNamespace::CollectionOwner),
+				(account(1), AttributeNamespace::ItemOwner),
+				(account(2), AttributeNamespace::Account(account(2))),
+			] {
+				assert_noop!(
+					Nfts::set_attribute(
+						RuntimeOrigin::signed(origin.clone()),
+						0,
+						Some(item),
+						namespace.clone(),
+						bvec![0],
+						bvec![0],
+					),
+					Error::<Test>::UnknownItem
+				);
+				assert_noop!(
+					Nfts::set_attributes(
+						RuntimeOrigin::signed(origin),
+						0,
+						Some(item),
+						namespace,
+						bvec![(bvec![0], bvec![0])],
+					),
+					Error::<Test>::UnknownItem
+				);
+			}
+		}
 	});
 }
 
@@ -1328,10 +5133,25 @@ fn preserve_config_for_frozen_items() {
 		);
 		let config = ItemConfigOf::<Test>::get(0, 0).unwrap();
 		assert_eq!(config, expect_config);
+		assert_eq!(
+			Nfts::item_settings(0, 0),
+			Some((ItemSetting::UnlockedAttributes | ItemSetting::UnlockedMetadata).bits())
+		);
+		assert_eq!(
+			Nfts::item_info(0, 0),
+			Some(ItemInfo {
+				owner: account(1),
+				metadata: None,
+				settings: (ItemSetting::UnlockedAttributes | ItemSetting::UnlockedMetadata).bits(),
+				attribute_count: 0,
+			})
+		);
 
 		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 0, Some(account(1))));
 		let config = ItemConfigOf::<Test>::get(0, 0).unwrap();
 		assert_eq!(config, expect_config);
+		assert_eq!(Nfts::item_settings(0, 0), None);
+		assert_eq!(Nfts::item_info(0, 0), None);
 
 		// can't mint with the different config
 		assert_noop!(
@@ -3010,6 +6830,288 @@ fn add_remove_item_attributes_approval_should_work() {
 	})
 }
 
+#[test]
+fn approve_item_attributes_batch_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let collection_id = 0;
+		let item_id = 0;
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None
+		));
+		assert_noop!(
+			Nfts::approve_item_attributes_batch(
+				RuntimeOrigin::signed(account(2)),
+				collection_id,
+				item_id,
+				bvec![account(2)],
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::approve_item_attributes_batch(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			bvec![account(2), account(3)],
+		));
+		assert_eq!(item_attributes_approvals(collection_id, item_id), vec![account(2), account(3)]);
+		for delegate in [account(2), account(3)] {
+			assert!(events().contains(&Event::<Test>::ItemAttributesApprovalAdded {
+				collection: collection_id,
+				item: item_id,
+				delegate,
+			}));
+		}
+
+		// nothing is approved if the limit would be exceeded
+		assert_ok!(Nfts::cancel_item_attributes_approval(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			account(3),
+			CancelAttributesApprovalWitness { account_attributes: 0 },
+		));
+		assert_noop!(
+			Nfts::approve_item_attributes_batch(
+				RuntimeOrigin::signed(user_1),
+				collection_id,
+				item_id,
+				bvec![account(3), account(4)],
+			),
+			Error::<Test>::ReachedApprovalLimit
+		);
+		assert_eq!(item_attributes_approvals(collection_id, item_id), vec![account(2)]);
+	})
+}
+
+#[test]
+fn item_attributes_approval_near_limit_event_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let collection_id = 0;
+		let item_id = 0;
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None
+		));
+
+		// no warning while the approvals are far from the limit
+		assert_ok!(Nfts::approve_item_attributes(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			account(2),
+		));
+		assert!(!events()
+			.iter()
+			.any(|e| matches!(e, Event::<Test>::ItemAttributesApprovalNearLimit { .. })));
+
+		assert_ok!(Nfts::cancel_item_attributes_approval(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			account(2),
+			CancelAttributesApprovalWitness { account_attributes: 0 },
+		));
+
+		ItemAttributesApprovalsNearLimitThreshold::set(&1);
+		assert_ok!(Nfts::approve_item_attributes(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			account(2),
+		));
+		assert!(events().contains(&Event::<Test>::ItemAttributesApprovalNearLimit {
+			collection: collection_id,
+			item: item_id,
+			remaining: 1,
+		}));
+
+		assert_ok!(Nfts::approve_item_attributes_batch(
+			RuntimeOrigin::signed(user_1),
+			collection_id,
+			item_id,
+			bvec![account(3)],
+		));
+		assert!(events().contains(&Event::<Test>::ItemAttributesApprovalNearLimit {
+			collection: collection_id,
+			item: item_id,
+			remaining: 0,
+		}));
+	});
+}
+
+#[test]
+fn collection_attribute_editors_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		Balances::make_free_balance_be(&account(3), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(2), None));
+
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				Some(0),
+				AttributeNamespace::Account(account(3)),
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::add_collection_attribute_editor(RuntimeOrigin::signed(account(2)), 0, account(3)),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::add_collection_attribute_editor(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			account(3)
+		));
+		assert!(events().contains(&Event::<Test>::CollectionAttributeEditorAdded {
+			collection: 0,
+			editor: account(3),
+		}));
+
+		// the editor pays the deposits even if the item's owner sponsors its delegates
+		assert_ok!(Nfts::set_delegate_attributes_sponsorship(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			0,
+			true
+		));
+		for item in [0, 1] {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				Some(item),
+				AttributeNamespace::Account(account(3)),
+				bvec![0],
+				bvec![0],
+			));
+		}
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+		assert_eq!(Balances::reserved_balance(account(3)), 6);
+
+		// the editor's namespace is enumerated along with the delegates' ones
+		assert_eq!(Nfts::item_attribute_accounts(0, 0), vec![account(3)]);
+		assert_eq!(
+			Nfts::attribute_namespaces(0, 0, vec![0]),
+			vec![(AttributeNamespace::Account(account(3)), vec![0])]
+		);
+
+		// the editor is restricted to its own namespace
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Nfts::add_collection_attribute_editor(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			account(4)
+		));
+		assert_noop!(
+			Nfts::add_collection_attribute_editor(RuntimeOrigin::signed(account(1)), 0, account(5)),
+			Error::<Test>::ReachedCollectionAttributeEditorsLimit
+		);
+
+		assert_ok!(Nfts::remove_collection_attribute_editor(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			account(3)
+		));
+		assert!(events().contains(&Event::<Test>::CollectionAttributeEditorRemoved {
+			collection: 0,
+			editor: account(3),
+		}));
+		assert_noop!(
+			Nfts::remove_collection_attribute_editor(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				account(3)
+			),
+			Error::<Test>::NotCollectionAttributeEditor
+		);
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				Some(0),
+				AttributeNamespace::Account(account(3)),
+				bvec![1],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission
+		);
+
+		// the item's owner can clear the attributes set by the former editor
+		assert_eq!(Balances::reserved_balance(account(3)), 6);
+		assert_ok!(Nfts::cancel_item_attributes_approval(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			0,
+			account(3),
+			CancelAttributesApprovalWitness { account_attributes: 1 },
+		));
+		assert_eq!(Balances::reserved_balance(account(3)), 3);
+	});
+}
+
+#[test]
+fn construct_path_key_should_work() {
+	new_test_ext().execute_with(|| {
+		let key = Nfts::construct_path_key(&[b"stats", b"strength"]).map(|key| key.into_inner());
+		assert_eq!(key, Ok(b"stats/strength".to_vec()));
+		let key = Nfts::construct_path_key(&[b"name"]).map(|key| key.into_inner());
+		assert_eq!(key, Ok(b"name".to_vec()));
+
+		assert_noop!(Nfts::construct_path_key(&[]), Error::<Test>::InvalidPathSegment);
+		assert_noop!(Nfts::construct_path_key(&[b"stats", b""]), Error::<Test>::InvalidPathSegment);
+		assert_noop!(
+			Nfts::construct_path_key(&[b"stats/strength", b"base"]),
+			Error::<Test>::InvalidPathSegment
+		);
+		assert_noop!(
+			Nfts::construct_path_key(&[&[b'a'; 25], &[b'b'; 25]]),
+			Error::<Test>::PathTooLong
+		);
+	});
+}
+
 #[test]
 fn pre_signed_mints_should_work() {
 	new_test_ext().execute_with(|| {
@@ -3266,6 +7368,8 @@ fn pre_signed_attributes_should_work() {
 
 		// validate we don't partially modify the state
 		assert_eq!(item_attributes_approvals(collection_id, item_id), vec![]);
+		assert_eq!(Nfts::attribute_key_limit(), 50);
+		assert_eq!(Nfts::attribute_value_limit(), 50);
 		let pre_signed_data = PreSignedAttributes {
 			collection: 0,
 			item: 0,
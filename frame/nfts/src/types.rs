@@ -24,7 +24,8 @@ use enumflags2::{bitflags, BitFlags};
 use frame_support::{
 	pallet_prelude::{BoundedVec, MaxEncodedLen},
 	traits::Get,
-	BoundedBTreeMap, BoundedBTreeSet,
+	BoundedBTreeMap, BoundedBTreeSet, CloneNoBound, EqNoBound, PartialEqNoBound,
+	RuntimeDebugNoBound,
 };
 use scale_info::{build::Fields, meta_type, Path, Type, TypeInfo, TypeParameter};
 
@@ -39,12 +40,18 @@ pub(super) type ApprovalsOf<T, I = ()> = BoundedBTreeMap<
 >;
 pub(super) type ItemAttributesApprovals<T, I = ()> =
 	BoundedBTreeSet<<T as SystemConfig>::AccountId, <T as Config<I>>::ItemAttributesApprovalsLimit>;
+pub(super) type CollectionAttributeEditors<T, I = ()> = BoundedBTreeSet<
+	<T as SystemConfig>::AccountId,
+	<T as Config<I>>::CollectionAttributeEditorsLimit,
+>;
 pub(super) type ItemDepositOf<T, I> =
 	ItemDeposit<DepositBalanceOf<T, I>, <T as SystemConfig>::AccountId>;
 pub(super) type AttributeDepositOf<T, I> =
 	AttributeDeposit<DepositBalanceOf<T, I>, <T as SystemConfig>::AccountId>;
 pub(super) type ItemMetadataDepositOf<T, I> =
 	ItemMetadataDeposit<DepositBalanceOf<T, I>, <T as SystemConfig>::AccountId>;
+pub(super) type ValueConstraintOf<T, I> =
+	ValueConstraint<<T as Config<I>>::ValueLimit, <T as Config<I>>::MaxAttributeValueOptions>;
 pub(super) type ItemDetailsFor<T, I> =
 	ItemDetails<<T as SystemConfig>::AccountId, ItemDepositOf<T, I>, ApprovalsOf<T, I>>;
 pub(super) type BalanceOf<T, I = ()> =
@@ -212,6 +219,149 @@ pub struct AttributeDeposit<DepositBalance, AccountId> {
 	pub(super) amount: DepositBalance,
 }
 
+/// The kind of an attribute's namespace, regardless of the account it belongs to.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum NamespaceDiscriminant {
+	/// The `Pallet` namespace.
+	Pallet,
+	/// The `CollectionOwner` namespace.
+	CollectionOwner,
+	/// The `ItemOwner` namespace.
+	ItemOwner,
+	/// Any of the `Account` namespaces.
+	Account,
+}
+
+impl<AccountId> From<&AttributeNamespace<AccountId>> for NamespaceDiscriminant {
+	fn from(namespace: &AttributeNamespace<AccountId>) -> Self {
+		match namespace {
+			AttributeNamespace::Pallet => Self::Pallet,
+			AttributeNamespace::CollectionOwner => Self::CollectionOwner,
+			AttributeNamespace::ItemOwner => Self::ItemOwner,
+			AttributeNamespace::Account(_) => Self::Account,
+		}
+	}
+}
+
+/// The type of an attribute's value, hinting consumers how to interpret the value's bytes.
+#[derive(
+	Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub enum AttributeValueType {
+	/// Opaque bytes.
+	#[default]
+	Raw,
+	/// UTF-8 encoded string.
+	Utf8,
+	/// SCALE-encoded `u128`.
+	U128,
+	/// SCALE-encoded `bool`.
+	Bool,
+	/// UTF-8 encoded JSON document.
+	Json,
+	/// SCALE-encoded `u32` number of chunks the large attribute's value is split into.
+	ChunkManifest,
+	/// Opaque bytes compressed with the `Config::AttributeCompressor`.
+	Compressed,
+}
+
+/// The item's core details, gathered for external consumers.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ItemInfo<AccountId> {
+	/// The owner of the item.
+	pub owner: AccountId,
+	/// The item's metadata, if it was set.
+	pub metadata: Option<Vec<u8>>,
+	/// The bitflags of the item's disabled settings.
+	pub settings: u64,
+	/// The number of attributes the item holds across all the namespaces.
+	pub attribute_count: u32,
+}
+
+/// A change of the item's attribute, as reported by the pallet's events.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AttributeChange<AccountId> {
+	/// The namespace of the attribute.
+	pub namespace: AttributeNamespace<AccountId>,
+	/// The attribute's key.
+	pub key: Vec<u8>,
+	/// The new value of the attribute, `None` means the attribute was cleared.
+	pub value: Option<Vec<u8>>,
+}
+
+/// The constraint the value of a `CollectionOwner` namespace attribute must satisfy.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(ValueLimit, MaxOptions))]
+#[codec(mel_bound())]
+pub enum ValueConstraint<ValueLimit: Get<u32>, MaxOptions: Get<u32>> {
+	/// The value must be a valid UTF-8 string.
+	Utf8,
+	/// The value can't be longer than the provided number of bytes.
+	MaxLen(u32),
+	/// The value must be equal to one of the provided options.
+	OneOf(BoundedVec<BoundedVec<u8, ValueLimit>, MaxOptions>),
+	/// The value must be a non-empty string of ASCII decimal digits.
+	Numeric,
+}
+
+impl<ValueLimit: Get<u32>, MaxOptions: Get<u32>> ValueConstraint<ValueLimit, MaxOptions> {
+	/// Checks whether the `value` satisfies the constraint.
+	pub fn is_satisfied_by(&self, value: &[u8]) -> bool {
+		match self {
+			Self::Utf8 => sp_std::str::from_utf8(value).is_ok(),
+			Self::MaxLen(max_len) => value.len() <= *max_len as usize,
+			Self::OneOf(options) => options.iter().any(|option| &option[..] == value),
+			Self::Numeric => !value.is_empty() && value.iter().all(u8::is_ascii_digit),
+		}
+	}
+
+	/// Returns the number of the options listed by the `OneOf` constraint.
+	pub fn options_len(&self) -> u32 {
+		match self {
+			Self::OneOf(options) => options.len() as u32,
+			_ => 0,
+		}
+	}
+}
+
+/// The reason a query for an item failed.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum ItemQueryError {
+	/// The collection doesn't exist.
+	UnknownCollection,
+	/// The item doesn't exist within the collection.
+	UnknownItem,
+}
+
+/// The change of the deposit reserved from an account.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum DepositDelta<DepositBalance> {
+	/// The amount is going to be additionally reserved.
+	Reserve(DepositBalance),
+	/// The amount is going to be returned.
+	Unreserve(DepositBalance),
+}
+
+/// The outcome of setting an attribute, computed without applying it.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SetAttributeEffect<AccountId, DepositBalance> {
+	/// The change of the deposit reserved from the `deposit_account`.
+	pub deposit_delta: DepositDelta<DepositBalance>,
+	/// Whether the attribute doesn't exist yet and is going to be created.
+	pub will_create: bool,
+	/// The account the attribute's deposit is going to be reserved from.
+	pub deposit_account: AccountId,
+}
+
 /// Information about the reserved item's metadata deposit.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct ItemMetadataDeposit<DepositBalance, AccountId> {
@@ -324,6 +474,34 @@ pub struct CancelAttributesApprovalWitness {
 	pub account_attributes: u32,
 }
 
+/// A witness data to clear all the item's attributes within a namespace.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ClearItemNamespaceWitness {
+	/// An amount of attributes stored within the namespace.
+	pub attributes: u32,
+}
+
+/// A witness data to transfer the deposits of the item's `ItemOwner` attributes.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct TransferItemAttributesWitness {
+	/// An amount of attributes stored within the `ItemOwner` namespace of the item.
+	pub attributes: u32,
+}
+
+/// A witness data to clear all the attributes of a collection in batches.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AttributeCleanupWitness {
+	/// The max amount of attributes to clear within a single call.
+	pub attributes: u32,
+}
+
+/// A witness data to clear the attributes of a burned item.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ClearOrphanedAttributesWitness {
+	/// An amount of attributes stored for the item across all the namespaces.
+	pub attributes: u32,
+}
+
 /// A list of possible pallet-level attributes.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum PalletAttributes<CollectionId> {
@@ -370,6 +548,9 @@ pub enum ItemSetting {
 	UnlockedMetadata,
 	/// Attributes of this item can be modified.
 	UnlockedAttributes,
+	/// Delegated accounts pay the deposit for the attributes they set. When disabled, the
+	/// deposit is paid by the item's owner instead.
+	DelegatesPayDeposit,
 }
 
 /// Wrapper type for `BitFlags<ItemSetting>` that implements `Codec`.
@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-osnnfcqu-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! NOTE: the weights marked as `PLACEHOLDER` weren't produced by the benchmarks. They were
+//! estimated by hand after their calls had been added or changed, and have to be replaced by
+//! regenerating this file with the command below before any production runtime relies on them.
 
 // Executed Command:
 // target/production/substrate
@@ -88,6 +92,17 @@ pub trait WeightInfo {
 	fn claim_swap() -> Weight;
 	fn mint_pre_signed(n: u32, ) -> Weight;
 	fn set_attributes_pre_signed(n: u32, ) -> Weight;
+	fn set_attributes(n: u32, ) -> Weight;
+	fn recalculate_attribute_deposit() -> Weight;
+	fn clear_item_namespace(n: u32, ) -> Weight;
+	fn transfer_item_attributes(n: u32, ) -> Weight;
+	fn clear_orphaned_attributes(n: u32, ) -> Weight;
+	fn set_delegate_attributes_sponsorship() -> Weight;
+	fn lock_attribute() -> Weight;
+	fn set_attribute_schema(n: u32, ) -> Weight;
+	fn clear_collection_attributes(n: u32, ) -> Weight;
+	fn add_collection_attribute_editor() -> Weight;
+	fn remove_collection_attribute_editor() -> Weight;
 }
 
 /// Weights for pallet_nfts using the Substrate node and recommended hardware.
@@ -411,37 +426,48 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
 	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts LockedAttributeKeysOf (r:1 w:0)
+	/// Storage: Nfts AttributeSchemaOf (r:1 w:0)
 	/// Storage: Nfts Attribute (r:1 w:1)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts CollectionNamespaceAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts AttributeValueTypeOf (r:1 w:1)
+	/// Storage: Nfts AttributeExpiryOf (r:1 w:1)
+	/// Storage: Nfts AttributeWritesInBlock (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts AttributeLastSetOf (r:0 w:1)
 	fn set_attribute() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `474`
-		//  Estimated: `10547`
-		// Minimum execution time: 40_925 nanoseconds.
 		Weight::from_ref_time(42_733_000)
-			.saturating_add(Weight::from_proof_size(10547))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_proof_size(16486))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts Attribute (r:1 w:1)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts CollectionNamespaceAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts AttributeValueTypeOf (r:0 w:1)
+	/// Storage: Nfts AttributeExpiryOf (r:0 w:1)
+	/// Storage: Nfts AttributeLastSetOf (r:0 w:1)
 	fn force_set_attribute() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `337`
-		//  Estimated: `5476`
-		// Minimum execution time: 24_486 nanoseconds.
 		Weight::from_ref_time(25_409_000)
 			.saturating_add(Weight::from_proof_size(5476))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Nfts Attribute (r:1 w:1)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
@@ -816,6 +842,205 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_proof_size(2921).saturating_mul(n.into()))
 	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts LockedAttributeKeysOf (r:10 w:0)
+	/// Storage: Nfts AttributeSchemaOf (r:10 w:0)
+	/// Storage: Nfts Attribute (r:10 w:10)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts CollectionNamespaceAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts AttributeValueTypeOf (r:10 w:10)
+	/// Storage: Nfts AttributeExpiryOf (r:10 w:10)
+	/// Storage: Nfts AttributeWritesInBlock (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts AttributeLastSetOf (r:0 w:10)
+	/// The range of component `n` is `[1, 10]`.
+	fn set_attributes(n: u32, ) -> Weight {
+		Weight::from_ref_time(18_330_000)
+			.saturating_add(Weight::from_proof_size(13565))
+			.saturating_add(Weight::from_ref_time(24_403_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(2921).saturating_mul(n.into()))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:1 w:1)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn recalculate_attribute_deposit() -> Weight {
+		Weight::from_ref_time(42_733_000)
+			.saturating_add(Weight::from_proof_size(10627))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts LockedAttributeKeysOf (r:1 w:0)
+	/// Storage: Nfts Attribute (r:101 w:100)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts AttributeValueTypeOf (r:0 w:100)
+	/// Storage: Nfts AttributeExpiryOf (r:0 w:100)
+	/// Storage: Nfts AttributeLastSetOf (r:0 w:100)
+	/// Storage: Nfts ItemAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts CollectionNamespaceAttributeCountOf (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn clear_item_namespace(n: u32, ) -> Weight {
+		Weight::from_ref_time(26_107_000)
+			.saturating_add(Weight::from_proof_size(10602))
+			.saturating_add(Weight::from_ref_time(9_030_830).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(2921).saturating_mul(n.into()))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:101 w:100)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn transfer_item_attributes(n: u32, ) -> Weight {
+		Weight::from_ref_time(26_107_000)
+			.saturating_add(Weight::from_proof_size(8860))
+			.saturating_add(Weight::from_ref_time(18_061_660).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(2921).saturating_mul(n.into()))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:101 w:100)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts LockedAttributeKeysOf (r:100 w:0)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts AttributeValueTypeOf (r:0 w:100)
+	/// Storage: Nfts AttributeExpiryOf (r:0 w:100)
+	/// Storage: Nfts AttributeLastSetOf (r:0 w:100)
+	/// Storage: Nfts ItemAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts CollectionNamespaceAttributeCountOf (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn clear_orphaned_attributes(n: u32, ) -> Weight {
+		Weight::from_ref_time(27_412_000)
+			.saturating_add(Weight::from_proof_size(13963))
+			.saturating_add(Weight::from_ref_time(9_315_204).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(2921).saturating_mul(n.into()))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn set_delegate_attributes_sponsorship() -> Weight {
+		Weight::from_ref_time(17_615_000)
+			.saturating_add(Weight::from_proof_size(5859))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:1 w:0)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts LockedAttributeKeysOf (r:0 w:1)
+	fn lock_attribute() -> Weight {
+		Weight::from_ref_time(19_132_000)
+			.saturating_add(Weight::from_proof_size(5476))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts AttributeSchemaOf (r:1 w:1)
+	/// Storage: Nfts AttributeSchemasCountOf (r:1 w:1)
+	/// The range of component `n` is `[0, 32]`.
+	fn set_attribute_schema(n: u32, ) -> Weight {
+		Weight::from_ref_time(21_086_000)
+			.saturating_add(Weight::from_proof_size(2555))
+			.saturating_add(Weight::from_ref_time(412_318).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:100 w:100)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: System Account (r:100 w:100)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts AttributeValueTypeOf (r:0 w:100)
+	/// Storage: Nfts AttributeExpiryOf (r:0 w:100)
+	/// Storage: Nfts AttributeLastSetOf (r:0 w:100)
+	/// Storage: Nfts ItemAttributeCountOf (r:100 w:100)
+	/// Storage: Nfts ItemAttributesDelegateCount (r:100 w:100)
+	/// Storage: Nfts CollectionNamespaceAttributeCountOf (r:100 w:100)
+	/// The range of component `n` is `[0, 100]`.
+	fn clear_collection_attributes(n: u32, ) -> Weight {
+		Weight::from_ref_time(21_384_000)
+			.saturating_add(Weight::from_proof_size(2555))
+			.saturating_add(Weight::from_ref_time(12_105_318).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(5524).saturating_mul(n.into()))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAttributeEditorsOf (r:1 w:1)
+	fn add_collection_attribute_editor() -> Weight {
+		Weight::from_ref_time(17_480_000)
+			.saturating_add(Weight::from_proof_size(2555))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAttributeEditorsOf (r:1 w:1)
+	fn remove_collection_attribute_editor() -> Weight {
+		Weight::from_ref_time(18_212_000)
+			.saturating_add(Weight::from_proof_size(2555))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -1138,37 +1363,48 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
 	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts LockedAttributeKeysOf (r:1 w:0)
+	/// Storage: Nfts AttributeSchemaOf (r:1 w:0)
 	/// Storage: Nfts Attribute (r:1 w:1)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts CollectionNamespaceAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts AttributeValueTypeOf (r:1 w:1)
+	/// Storage: Nfts AttributeExpiryOf (r:1 w:1)
+	/// Storage: Nfts AttributeWritesInBlock (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts AttributeLastSetOf (r:0 w:1)
 	fn set_attribute() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `474`
-		//  Estimated: `10547`
-		// Minimum execution time: 40_925 nanoseconds.
 		Weight::from_ref_time(42_733_000)
-			.saturating_add(Weight::from_proof_size(10547))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_proof_size(16486))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts Attribute (r:1 w:1)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts CollectionNamespaceAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts AttributeValueTypeOf (r:0 w:1)
+	/// Storage: Nfts AttributeExpiryOf (r:0 w:1)
+	/// Storage: Nfts AttributeLastSetOf (r:0 w:1)
 	fn force_set_attribute() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `337`
-		//  Estimated: `5476`
-		// Minimum execution time: 24_486 nanoseconds.
 		Weight::from_ref_time(25_409_000)
 			.saturating_add(Weight::from_proof_size(5476))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: Nfts Attribute (r:1 w:1)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
@@ -1543,4 +1779,203 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_proof_size(2921).saturating_mul(n.into()))
 	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts LockedAttributeKeysOf (r:10 w:0)
+	/// Storage: Nfts AttributeSchemaOf (r:10 w:0)
+	/// Storage: Nfts Attribute (r:10 w:10)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts CollectionNamespaceAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts AttributeValueTypeOf (r:10 w:10)
+	/// Storage: Nfts AttributeExpiryOf (r:10 w:10)
+	/// Storage: Nfts AttributeWritesInBlock (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts AttributeLastSetOf (r:0 w:10)
+	/// The range of component `n` is `[1, 10]`.
+	fn set_attributes(n: u32, ) -> Weight {
+		Weight::from_ref_time(18_330_000)
+			.saturating_add(Weight::from_proof_size(13565))
+			.saturating_add(Weight::from_ref_time(24_403_000).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(2921).saturating_mul(n.into()))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:1 w:1)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn recalculate_attribute_deposit() -> Weight {
+		Weight::from_ref_time(42_733_000)
+			.saturating_add(Weight::from_proof_size(10627))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts LockedAttributeKeysOf (r:1 w:0)
+	/// Storage: Nfts Attribute (r:101 w:100)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts AttributeValueTypeOf (r:0 w:100)
+	/// Storage: Nfts AttributeExpiryOf (r:0 w:100)
+	/// Storage: Nfts AttributeLastSetOf (r:0 w:100)
+	/// Storage: Nfts ItemAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts CollectionNamespaceAttributeCountOf (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn clear_item_namespace(n: u32, ) -> Weight {
+		Weight::from_ref_time(26_107_000)
+			.saturating_add(Weight::from_proof_size(10602))
+			.saturating_add(Weight::from_ref_time(9_030_830).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(2921).saturating_mul(n.into()))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:101 w:100)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn transfer_item_attributes(n: u32, ) -> Weight {
+		Weight::from_ref_time(26_107_000)
+			.saturating_add(Weight::from_proof_size(8860))
+			.saturating_add(Weight::from_ref_time(18_061_660).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(2921).saturating_mul(n.into()))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:101 w:100)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts LockedAttributeKeysOf (r:100 w:0)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts AttributeValueTypeOf (r:0 w:100)
+	/// Storage: Nfts AttributeExpiryOf (r:0 w:100)
+	/// Storage: Nfts AttributeLastSetOf (r:0 w:100)
+	/// Storage: Nfts ItemAttributeCountOf (r:1 w:1)
+	/// Storage: Nfts CollectionNamespaceAttributeCountOf (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn clear_orphaned_attributes(n: u32, ) -> Weight {
+		Weight::from_ref_time(27_412_000)
+			.saturating_add(Weight::from_proof_size(13963))
+			.saturating_add(Weight::from_ref_time(9_315_204).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(2921).saturating_mul(n.into()))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn set_delegate_attributes_sponsorship() -> Weight {
+		Weight::from_ref_time(17_615_000)
+			.saturating_add(Weight::from_proof_size(5859))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:1 w:0)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts LockedAttributeKeysOf (r:0 w:1)
+	fn lock_attribute() -> Weight {
+		Weight::from_ref_time(19_132_000)
+			.saturating_add(Weight::from_proof_size(5476))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts AttributeSchemaOf (r:1 w:1)
+	/// Storage: Nfts AttributeSchemasCountOf (r:1 w:1)
+	/// The range of component `n` is `[0, 32]`.
+	fn set_attribute_schema(n: u32, ) -> Weight {
+		Weight::from_ref_time(21_086_000)
+			.saturating_add(Weight::from_proof_size(2555))
+			.saturating_add(Weight::from_ref_time(412_318).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:100 w:100)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: System Account (r:100 w:100)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts AttributeValueTypeOf (r:0 w:100)
+	/// Storage: Nfts AttributeExpiryOf (r:0 w:100)
+	/// Storage: Nfts AttributeLastSetOf (r:0 w:100)
+	/// Storage: Nfts ItemAttributeCountOf (r:100 w:100)
+	/// Storage: Nfts ItemAttributesDelegateCount (r:100 w:100)
+	/// Storage: Nfts CollectionNamespaceAttributeCountOf (r:100 w:100)
+	/// The range of component `n` is `[0, 100]`.
+	fn clear_collection_attributes(n: u32, ) -> Weight {
+		Weight::from_ref_time(21_384_000)
+			.saturating_add(Weight::from_proof_size(2555))
+			.saturating_add(Weight::from_ref_time(12_105_318).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((8_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(5524).saturating_mul(n.into()))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAttributeEditorsOf (r:1 w:1)
+	fn add_collection_attribute_editor() -> Weight {
+		Weight::from_ref_time(17_480_000)
+			.saturating_add(Weight::from_proof_size(2555))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// PLACEHOLDER: estimated by hand, not produced by the benchmarks.
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAttributeEditorsOf (r:1 w:1)
+	fn remove_collection_attribute_editor() -> Weight {
+		Weight::from_ref_time(18_212_000)
+			.saturating_add(Weight::from_proof_size(2555))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,62 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Keys of the attributes conventionally used across collections.
+
+use sp_runtime::RuntimeDebug;
+
+/// The name of the collection or item.
+pub const NAME: &[u8] = b"name";
+/// The description of the collection or item.
+pub const DESCRIPTION: &[u8] = b"description";
+/// The link to the image of the collection or item.
+pub const IMAGE: &[u8] = b"image";
+/// The link to the off-chain metadata of the collection or item.
+pub const EXTERNAL_URI: &[u8] = b"external_uri";
+/// The royalty the creator of the collection or item is entitled to.
+pub const ROYALTY: &[u8] = b"royalty";
+
+/// The separator of the segments of the hierarchical attribute keys, e.g. `stats/strength`.
+pub const PATH_SEPARATOR: u8 = b'/';
+
+/// The conventional attribute keys.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum WellKnownKey {
+	/// See [`NAME`].
+	Name,
+	/// See [`DESCRIPTION`].
+	Description,
+	/// See [`IMAGE`].
+	Image,
+	/// See [`EXTERNAL_URI`].
+	ExternalUri,
+	/// See [`ROYALTY`].
+	Royalty,
+}
+
+impl WellKnownKey {
+	/// Returns the raw attribute key.
+	pub fn as_bytes(&self) -> &'static [u8] {
+		match self {
+			Self::Name => NAME,
+			Self::Description => DESCRIPTION,
+			Self::Image => IMAGE,
+			Self::ExternalUri => EXTERNAL_URI,
+			Self::Royalty => ROYALTY,
+		}
+	}
+}